@@ -110,7 +110,7 @@ const SRC_WINDOWS: [&str; 61] = [
     "win_public.cpp",
     "win_util.cpp",
 ];
-const SRC_UNIX: [&str; 44] = [
+const SRC_UNIX: [&str; 48] = [
     "alloc.c",
     "area.c",
     "attr_str.c",
@@ -119,6 +119,7 @@ const SRC_UNIX: [&str; 44] = [
     "cell_renderer_button.c",
     "checkbox.c",
     "child.c",
+    "clipboard.c",
     "color_button.c",
     "combobox.c",
     "control.c",
@@ -128,8 +129,10 @@ const SRC_UNIX: [&str; 44] = [
     "drawmatrix.c",
     "draw_path.c",
     "drawtext.c",
+    "drop.c",
     "editable_combo.c",
     "entry.c",
+    "focus.c",
     "font_button.c",
     "font_match.c",
     "form.c",
@@ -149,6 +152,7 @@ const SRC_UNIX: [&str; 44] = [
     "slider.c",
     "spinbox.c",
     "std_dialogs.c",
+    "style.c",
     "tab.c",
     "table.c",
     "tablemodel.c",
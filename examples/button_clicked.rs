@@ -14,13 +14,6 @@ fn main() -> anyhow::Result<()> {
         &mut cnt,
     )?;
     window.set_child(button);
-    window.on_closing(
-        |_, _| {
-            quit_loop();
-            true
-        },
-        &mut (),
-    )?;
 
     Ok(main_loop())
 }
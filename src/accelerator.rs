@@ -0,0 +1,257 @@
+#[cfg(target_os = "linux")]
+use crate::raw::{
+    uiExtKeyDelete, uiExtKeyDown, uiExtKeyEnd, uiExtKeyEscape, uiExtKeyF1, uiExtKeyHome,
+    uiExtKeyInsert, uiExtKeyLeft, uiExtKeyN0, uiExtKeyNAdd, uiExtKeyNDivide, uiExtKeyNDot,
+    uiExtKeyNEnter, uiExtKeyNMultiply, uiExtKeyNSubtract, uiExtKeyPageDown, uiExtKeyPageUp,
+    uiExtKeyRight, uiExtKeyUp,
+};
+use {
+    crate::{
+        error::UiError,
+        raw::{uiModifierAlt, uiModifierCtrl, uiModifierShift, uiModifierSuper},
+    },
+    std::{
+        fmt::{Display, Formatter, Result as FmtResult},
+        ops::{BitOr, BitOrAssign},
+        str::FromStr,
+    },
+};
+
+/// Keyboard modifier keys.
+/// Usable as bitmasks.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct Modifiers(u32);
+
+impl Modifiers {
+    /// No modifier key.
+    pub const NONE: Self = Self(0);
+    /// Control key.
+    pub const CTRL: Self = Self(uiModifierCtrl as _);
+    /// Alternate/Option key.
+    pub const ALT: Self = Self(uiModifierAlt as _);
+    /// Shift key.
+    pub const SHIFT: Self = Self(uiModifierShift as _);
+    /// Super/Command/Windows key.
+    pub const SUPER: Self = Self(uiModifierSuper as _);
+
+    /// Returns the raw `uiModifiers` bitmask.
+    pub fn bits(&self) -> u32 {
+        self.0
+    }
+
+    /// Returns whether all modifiers of `other` are contained.
+    pub fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl BitOr for Modifiers {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Modifiers {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0
+    }
+}
+
+/// A key that can be part of an [`Accelerator`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Key {
+    /// A printable character. Letters are stored in lower case.
+    Char(char),
+    Escape,
+    Insert,
+    Delete,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Up,
+    Down,
+    Left,
+    Right,
+    /// Function key in the range `[1, 12]`.
+    F(u8),
+    /// Numpad digit in the range `[0, 9]`.
+    Numpad(u8),
+    NumpadDot,
+    NumpadEnter,
+    NumpadAdd,
+    NumpadSubtract,
+    NumpadMultiply,
+    NumpadDivide,
+}
+
+impl Key {
+    #[cfg(target_os = "linux")]
+    /// Returns the raw representation as a Unicode code point and a `uiExtKey`.
+    /// Exactly one of both is non-zero.
+    pub(crate) fn to_raw(self) -> (u32, i32) {
+        let ext = match self {
+            Self::Char(c) => return (c as _, 0),
+            Self::Escape => uiExtKeyEscape,
+            Self::Insert => uiExtKeyInsert,
+            Self::Delete => uiExtKeyDelete,
+            Self::Home => uiExtKeyHome,
+            Self::End => uiExtKeyEnd,
+            Self::PageUp => uiExtKeyPageUp,
+            Self::PageDown => uiExtKeyPageDown,
+            Self::Up => uiExtKeyUp,
+            Self::Down => uiExtKeyDown,
+            Self::Left => uiExtKeyLeft,
+            Self::Right => uiExtKeyRight,
+            Self::F(n) => uiExtKeyF1 + n.clamp(1, 12) as u32 - 1,
+            Self::Numpad(n) => uiExtKeyN0 + n.min(9) as u32,
+            Self::NumpadDot => uiExtKeyNDot,
+            Self::NumpadEnter => uiExtKeyNEnter,
+            Self::NumpadAdd => uiExtKeyNAdd,
+            Self::NumpadSubtract => uiExtKeyNSubtract,
+            Self::NumpadMultiply => uiExtKeyNMultiply,
+            Self::NumpadDivide => uiExtKeyNDivide,
+        };
+        (0, ext as _)
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Char('+') => write!(f, "Plus"),
+            Self::Char(' ') => write!(f, "Space"),
+            Self::Char(c) => write!(f, "{}", c.to_uppercase()),
+            Self::F(n) => write!(f, "F{}", n),
+            Self::Numpad(n) => write!(f, "Num{}", n),
+            Self::PageUp => write!(f, "PgUp"),
+            Self::PageDown => write!(f, "PgDn"),
+            Self::Escape => write!(f, "Esc"),
+            Self::NumpadDot => write!(f, "NumDot"),
+            Self::NumpadEnter => write!(f, "NumEnter"),
+            Self::NumpadAdd => write!(f, "NumAdd"),
+            Self::NumpadSubtract => write!(f, "NumSubtract"),
+            Self::NumpadMultiply => write!(f, "NumMultiply"),
+            Self::NumpadDivide => write!(f, "NumDivide"),
+            _ => write!(f, "{:?}", self),
+        }
+    }
+}
+
+impl FromStr for Key {
+    type Err = UiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Ok(Self::Char(c.to_lowercase().next().unwrap_or(c)));
+        }
+
+        let name = s.to_ascii_lowercase();
+        let key = match name.as_str() {
+            "plus" => Self::Char('+'),
+            "space" => Self::Char(' '),
+            "esc" | "escape" => Self::Escape,
+            "ins" | "insert" => Self::Insert,
+            "del" | "delete" => Self::Delete,
+            "home" => Self::Home,
+            "end" => Self::End,
+            "pgup" | "pageup" => Self::PageUp,
+            "pgdn" | "pagedown" => Self::PageDown,
+            "up" => Self::Up,
+            "down" => Self::Down,
+            "left" => Self::Left,
+            "right" => Self::Right,
+            "numdot" => Self::NumpadDot,
+            "numenter" => Self::NumpadEnter,
+            "numadd" => Self::NumpadAdd,
+            "numsubtract" => Self::NumpadSubtract,
+            "nummultiply" => Self::NumpadMultiply,
+            "numdivide" => Self::NumpadDivide,
+            _ => match (name.strip_prefix('f'), name.strip_prefix("num")) {
+                (Some(n), _) => match n.parse() {
+                    Ok(n @ 1..=12) => Self::F(n),
+                    _ => return Err(UiError::Accelerator(s.into())),
+                },
+                (_, Some(n)) => match n.parse() {
+                    Ok(n @ 0..=9) => Self::Numpad(n),
+                    _ => return Err(UiError::Accelerator(s.into())),
+                },
+                _ => return Err(UiError::Accelerator(s.into())),
+            },
+        };
+        Ok(key)
+    }
+}
+
+/// A keyboard shortcut such as `Ctrl+S`.
+///
+/// Accelerators are parsed from strings of `+` separated modifiers followed by a key,
+/// e.g. `"Ctrl+Shift+P"`, `"Alt+F4"` or `"Esc"`. Names are case-insensitive.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Accelerator {
+    pub modifiers: Modifiers,
+    pub key: Key,
+}
+
+impl Accelerator {
+    /// Creates a new accelerator.
+    ///
+    /// # arguments
+    /// * `modifiers`: Modifier keys that have to be held.
+    /// * `key`: The key to press.
+    pub fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self { modifiers, key }
+    }
+}
+
+impl Display for Accelerator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for (modifier, name) in [
+            (Modifiers::CTRL, "Ctrl"),
+            (Modifiers::ALT, "Alt"),
+            (Modifiers::SHIFT, "Shift"),
+            (Modifiers::SUPER, "Super"),
+        ] {
+            if self.modifiers.contains(modifier) {
+                write!(f, "{}+", name)?;
+            }
+        }
+        Display::fmt(&self.key, f)
+    }
+}
+
+impl FromStr for Accelerator {
+    type Err = UiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // A trailing `+` is the plus key itself, e.g. `Ctrl++`.
+        let (s, plus) = match s.trim().strip_suffix("++") {
+            Some(rest) => (rest, true),
+            None => (s.trim(), false),
+        };
+        let mut parts = s.split('+').map(str::trim).collect::<Vec<_>>();
+        let key = match plus {
+            true => Key::Char('+'),
+            false => parts
+                .pop()
+                .filter(|p| !p.is_empty())
+                .ok_or_else(|| UiError::Accelerator(s.into()))?
+                .parse()?,
+        };
+
+        let mut modifiers = Modifiers::NONE;
+        for part in parts {
+            modifiers |= match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => Modifiers::CTRL,
+                "alt" | "option" => Modifiers::ALT,
+                "shift" => Modifiers::SHIFT,
+                "super" | "cmd" | "command" | "win" | "meta" => Modifiers::SUPER,
+                _ => return Err(UiError::Accelerator(s.into())),
+            };
+        }
+        Ok(Self { modifiers, key })
+    }
+}
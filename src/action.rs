@@ -0,0 +1,433 @@
+use {
+    crate::{
+        Accelerator, Button, Control, MenuItem, Window,
+        error::UiError,
+        modify_callback,
+        raw::{uiControl, uiControlOnDestroyed},
+        windows,
+    },
+    log::error,
+    std::{
+        collections::HashMap,
+        ffi::c_void,
+        ptr::null_mut,
+        sync::{Arc, Mutex, MutexGuard, TryLockError, Weak},
+    },
+};
+
+type Handler = Arc<Mutex<dyn FnMut(&Action) + Send>>;
+
+type WeakState = Weak<Mutex<State>>;
+
+/// Registered actions by id. Actions are forgotten once every handle was dropped.
+static ACTIONS: Mutex<Option<HashMap<String, WeakState>>> = Mutex::new(None);
+
+/// The actions each bound control belongs to, so destroyed controls can be forgotten.
+static BOUND: Mutex<Option<HashMap<isize, Vec<WeakState>>>> = Mutex::new(None);
+
+fn add_bound(ptr: isize, state: &Arc<Mutex<State>>) -> Result<bool, UiError> {
+    Ok(modify_callback!(BOUND, m, {
+        let states = m.entry(ptr).or_default();
+        states.push(Arc::downgrade(state));
+        Some(states.len() == 1)
+    })?
+    .unwrap_or_default())
+}
+
+/// Unbinds a menu item or control that is about to be deleted from all actions.
+pub(crate) fn forget_bound(ptr: isize) -> Result<(), UiError> {
+    let states = modify_callback!(BOUND, m, { m.remove(&ptr) })?;
+    for state in states.into_iter().flatten() {
+        if let Some(state) = state.upgrade() {
+            let mut state = state.lock()?;
+            state.menu_items.retain(|p| *p != ptr);
+            state.buttons.retain(|p| *p != ptr);
+        }
+    }
+    Ok(())
+}
+
+unsafe extern "C" fn on_destroyed(c: *mut uiControl, _data: *mut c_void) {
+    if let Err(e) = forget_bound(c as _) {
+        error!("An error was occurred in action on_destroyed: {}", e);
+    }
+}
+
+/// Removes destroyed windows from the bound windows.
+fn retain_alive(bound: &mut Vec<isize>) -> Result<(), UiError> {
+    let alive = windows()?;
+    bound.retain(|p| alive.iter().any(|w| w.as_ptr_mut() as isize == *p));
+    Ok(())
+}
+
+#[derive(Default)]
+struct State {
+    label: String,
+    enabled: bool,
+    checked: bool,
+    accelerator: Option<Accelerator>,
+    handler: Option<Handler>,
+    // Raw pointers of the bound controls, kept as integers so the state stays `Send`.
+    // Deleted menu items and destroyed buttons are removed through `forget_bound()`.
+    menu_items: Vec<isize>,
+    buttons: Vec<isize>,
+    windows: Vec<isize>,
+}
+
+/// A command shared by menu items, buttons and keyboard shortcuts.
+///
+/// Every control bound to an action triggers its handler, and changing the label,
+/// enabled or checked state of the action updates all bound controls.
+/// Cloned values refer to the same action.
+/// Only available on Linux for now.
+#[derive(Clone)]
+pub struct Action {
+    id: Arc<str>,
+    state: Arc<Mutex<State>>,
+}
+
+impl Action {
+    fn lock(&self) -> Result<MutexGuard<'_, State>, UiError> {
+        Ok(self.state.lock()?)
+    }
+
+    /// Returns the action id.
+    pub fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Returns the action label.
+    pub fn label(&self) -> Result<String, UiError> {
+        Ok(self.lock()?.label.clone())
+    }
+
+    /// Sets the action label.
+    ///
+    /// # arguments
+    /// * `label`: Text of all bound menu items and buttons.
+    pub fn set_label(&self, label: &str) -> Result<(), UiError> {
+        let mut state = self.lock()?;
+        for ptr in &state.menu_items {
+            MenuItem::from_ptr(*ptr as _).set_text(label)?;
+        }
+        for ptr in &state.buttons {
+            Button::from_ptr(*ptr as _).set_text(label)?;
+        }
+        state.label = label.into();
+        Ok(())
+    }
+
+    /// Returns whether the action is enabled. [Default: `true`]
+    pub fn enabled(&self) -> Result<bool, UiError> {
+        Ok(self.lock()?.enabled)
+    }
+
+    /// Enables or disables the action and every bound menu item and button.
+    ///
+    /// # arguments
+    /// * `enabled`: `true` to enable, `false` to disable.
+    pub fn set_enabled(&self, enabled: bool) -> Result<(), UiError> {
+        let mut state = self.lock()?;
+        state.enabled = enabled;
+        for ptr in &state.menu_items {
+            let item = MenuItem::from_ptr(*ptr as _);
+            match enabled {
+                true => item.enable(),
+                false => item.disable(),
+            }
+        }
+        for ptr in &state.buttons {
+            let button = Button::from_ptr(*ptr as _);
+            match enabled {
+                true => button.enable(),
+                false => button.disable(),
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns whether the action is checked.
+    pub fn checked(&self) -> Result<bool, UiError> {
+        Ok(self.lock()?.checked)
+    }
+
+    /// Sets whether the action is checked.
+    ///
+    /// # arguments
+    /// * `checked`: Check state of all bound menu items.
+    ///
+    /// # note
+    /// * Only menu items created via `append_check_item()` should be bound to checkable actions.
+    pub fn set_checked(&self, checked: bool) -> Result<(), UiError> {
+        let mut state = self.lock()?;
+        state.checked = checked;
+        for ptr in &state.menu_items {
+            MenuItem::from_ptr(*ptr as _).set_checked(checked);
+        }
+        Ok(())
+    }
+
+    /// Returns the keyboard shortcut of the action.
+    pub fn accelerator(&self) -> Result<Option<Accelerator>, UiError> {
+        Ok(self.lock()?.accelerator)
+    }
+
+    /// Sets the keyboard shortcut of the action.
+    ///
+    /// # arguments
+    /// * `accelerator`: The shortcut of all bound menu items and windows, `None` to remove it.
+    pub fn set_accelerator(&self, accelerator: Option<Accelerator>) -> Result<(), UiError> {
+        let mut state = self.lock()?;
+        let previous = state.accelerator;
+        state.accelerator = accelerator;
+        for ptr in &state.menu_items {
+            MenuItem::from_ptr(*ptr as _).set_accelerator(accelerator);
+        }
+        retain_alive(&mut state.windows)?;
+        let bound = state.windows.clone();
+        drop(state);
+
+        for ptr in bound {
+            let window = Window::from_ptr(ptr as _);
+            if let Some(a) = previous {
+                window.remove_shortcut(a)?;
+            }
+            if accelerator.is_some() {
+                self.add_shortcut(&window)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Registers the handler called when the action is triggered.
+    ///
+    /// # arguments
+    /// * `f`: Callback function.
+    ///          @p action The triggered action.
+    ///
+    /// # note
+    /// * Only one handler can be registered at a time.
+    pub fn on_triggered<F>(&self, f: F) -> Result<(), UiError>
+    where
+        F: FnMut(&Action) + Send + 'static,
+    {
+        self.lock()?.handler = Some(Arc::new(Mutex::new(f)));
+        Ok(())
+    }
+
+    /// Unregisters the handler of the action.
+    pub fn clear_triggered(&self) -> Result<(), UiError> {
+        self.lock()?.handler = None;
+        Ok(())
+    }
+
+    /// Calls the handler if the action is enabled.
+    ///
+    /// # returns
+    /// * `true` if the handler was called, `false` otherwise.
+    ///
+    /// # note
+    /// * Triggering the action again from within its own handler does nothing.
+    pub fn trigger(&self) -> Result<bool, UiError> {
+        let handler = {
+            let state = self.lock()?;
+            if !state.enabled {
+                return Ok(false);
+            }
+            match &state.handler {
+                Some(h) => h.clone(),
+                None => return Ok(false),
+            }
+        };
+
+        // The state is not kept locked, so the handler can modify or clear the action.
+        let mut handler = match handler.try_lock() {
+            Ok(h) => h,
+            Err(TryLockError::WouldBlock) => return Ok(false),
+            Err(TryLockError::Poisoned(e)) => return Err(e.into()),
+        };
+        handler(self);
+        Ok(true)
+    }
+
+    /// Binds a menu item to the action.
+    /// The item takes over the label, enabled and checked state and the shortcut of the action.
+    ///
+    /// # arguments
+    /// * `item`: The menu item.
+    ///
+    /// # note
+    /// * This replaces the `on_clicked` callback of the item.
+    /// * Deleting the item unbinds it.
+    pub fn bind_menu_item(&self, item: &MenuItem) -> Result<(), UiError> {
+        let mut state = self.lock()?;
+        item.set_text(&state.label)?;
+        match state.enabled {
+            true => item.enable(),
+            false => item.disable(),
+        }
+        if state.checked {
+            item.set_checked(true);
+        }
+        if state.accelerator.is_some() {
+            item.set_accelerator(state.accelerator);
+        }
+        state.menu_items.push(item.as_ptr_mut() as _);
+        drop(state);
+        add_bound(item.as_ptr_mut() as _, &self.state)?;
+
+        let action = self.clone();
+        item.on_clicked(
+            move |item, _, _| {
+                // Check items toggle themselves before the callback is called.
+                let checked = item.checked();
+                let result = match action.checked() {
+                    Ok(c) if c != checked => action.set_checked(checked),
+                    r => r.map(|_| ()),
+                }
+                .and_then(|_| action.trigger());
+                if let Err(e) = result {
+                    error!("An error was occurred in action {}: {}", action.id, e);
+                }
+            },
+            &mut (),
+        )
+    }
+
+    /// Binds a button to the action.
+    /// The button takes over the label and enabled state of the action.
+    ///
+    /// # arguments
+    /// * `button`: The button.
+    ///
+    /// # note
+    /// * This replaces the `on_clicked` callback of the button.
+    /// * Destroying the button unbinds it.
+    pub fn bind_button(&self, button: &Button) -> Result<(), UiError> {
+        let mut state = self.lock()?;
+        button.set_text(&state.label)?;
+        match state.enabled {
+            true => button.enable(),
+            false => button.disable(),
+        }
+        state.buttons.push(button.as_ptr_mut() as _);
+        drop(state);
+        if add_bound(button.as_ptr_mut() as _, &self.state)? {
+            unsafe { uiControlOnDestroyed(button.as_ptr_mut(), Some(on_destroyed), null_mut()) }
+        }
+
+        let action = self.clone();
+        button.on_clicked(
+            move |_, _| {
+                if let Err(e) = action.trigger() {
+                    error!("An error was occurred in action {}: {}", action.id, e);
+                }
+            },
+            &mut (),
+        )
+    }
+
+    /// Binds the shortcut of the action to a window.
+    /// Pressing the shortcut in the window triggers the action, also if the window has no menubar.
+    ///
+    /// # arguments
+    /// * `window`: The window.
+    ///
+    /// # note
+    /// * Changing the shortcut via `set_accelerator()` updates all bound windows.
+    /// * Windows showing a bound menu item do not need this, the menu item already handles the shortcut.
+    pub fn bind_shortcut(&self, window: &Window) -> Result<(), UiError> {
+        let mut state = self.lock()?;
+        let ptr = window.as_ptr_mut() as isize;
+        if !state.windows.contains(&ptr) {
+            state.windows.push(ptr);
+        }
+        drop(state);
+        self.add_shortcut(window)
+    }
+
+    fn add_shortcut(&self, window: &Window) -> Result<(), UiError> {
+        let Some(accelerator) = self.accelerator()? else {
+            return Ok(());
+        };
+        let action = self.clone();
+        window.add_shortcut(accelerator, move |_| {
+            if let Err(e) = action.trigger() {
+                error!("An error was occurred in action {}: {}", action.id, e);
+            }
+        })
+    }
+
+    /// Unbinds a menu item, button or window from the action.
+    ///
+    /// # arguments
+    /// * `control`: A previously bound menu item, button or window.
+    ///
+    /// # note
+    /// * The `on_clicked` callback of the control is not removed.
+    pub fn unbind<C, I>(&self, control: C) -> Result<(), UiError>
+    where
+        C: AsRef<I>,
+        I: Control,
+    {
+        let ptr = control.as_ref().as_ptr_mut() as isize;
+        let mut state = self.lock()?;
+        state.menu_items.retain(|p| *p != ptr);
+        state.buttons.retain(|p| *p != ptr);
+        // A destroyed window has no shortcuts left to remove.
+        retain_alive(&mut state.windows)?;
+        let window = state.windows.contains(&ptr);
+        state.windows.retain(|p| *p != ptr);
+        let accelerator = state.accelerator;
+        drop(state);
+
+        if let (true, Some(a)) = (window, accelerator) {
+            Window::from_ptr(ptr as _).remove_shortcut(a)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a registered action.
+    ///
+    /// # arguments
+    /// * `id`: The action id.
+    ///
+    /// # returns
+    /// * The action, `None` if no action with this id was created or it was dropped.
+    ///
+    /// # note
+    /// * The callbacks of bound menu items, buttons and windows keep the action alive.
+    pub fn find(id: &str) -> Result<Option<Self>, UiError> {
+        let state = modify_callback!(ACTIONS, a, { a.get(id).and_then(Weak::upgrade) })?;
+        Ok(state.map(|state| Self {
+            id: id.into(),
+            state,
+        }))
+    }
+
+    /// Creates a new enabled action and registers it by id.
+    /// An action registered before with the same id is replaced.
+    ///
+    /// # arguments
+    /// * `id`: Unique identifier, e.g. `"file.save"`.
+    /// * `label`: Text of bound menu items and buttons.
+    ///
+    /// # returns
+    /// * A new action.
+    pub fn new(id: &str, label: &str) -> Result<Self, UiError> {
+        let action = Self {
+            id: id.into(),
+            state: Arc::new(Mutex::new(State {
+                label: label.into(),
+                enabled: true,
+                ..Default::default()
+            })),
+        };
+        let weak = Arc::downgrade(&action.state);
+        modify_callback!(ACTIONS, a, {
+            a.retain(|_, s| s.strong_count() > 0);
+            a.insert(id.into(), weak)
+        })?;
+        Ok(action)
+    }
+}
@@ -17,6 +17,8 @@ impl AsRef<Self> for Box {
 }
 
 impl Control for Box {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x426F784C);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
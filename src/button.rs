@@ -1,3 +1,5 @@
+#[cfg(target_os = "linux")]
+use crate::raw::uiButtonClick;
 use {
     crate::{
         Control, define_callback_function,
@@ -29,6 +31,8 @@ impl AsRef<Self> for Button {
 }
 
 impl Control for Button {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x42746F6E);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
@@ -79,6 +83,16 @@ impl Button {
         self._on_clicked(Some(f), data)
     }
 
+    /// Clicks the button as if the user did, calling the registered callback.
+    ///
+    /// # note
+    /// * Disabled buttons are clicked as well.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn click(&self) {
+        unsafe { uiButtonClick(self._inner) }
+    }
+
     /// Unregisters a callback for when the button is clicked.
     pub fn clear_clicked(&self) -> Result<(), UiError> {
         #[allow(unused_assignments)]
@@ -29,6 +29,8 @@ impl AsRef<Self> for CheckBox {
 }
 
 impl Control for CheckBox {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x43686B62);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
@@ -0,0 +1,108 @@
+//! Access to the system clipboard.
+//!
+//! Only available on Linux for now.
+
+use {
+    crate::{
+        Image,
+        error::UiError,
+        raw::{
+            uiClipboardImage, uiClipboardOnChanged, uiClipboardSetImage, uiClipboardSetText,
+            uiClipboardText, uiFreeText,
+        },
+    },
+    log::error,
+    std::{
+        ffi::{CStr, CString, NulError, c_void},
+        ptr::null_mut,
+        str::Utf8Error,
+        sync::{Arc, Mutex},
+    },
+};
+
+type Changed = Arc<Mutex<dyn FnMut() + Send>>;
+
+static CHANGED: Mutex<Option<Changed>> = Mutex::new(None);
+
+/// Places text on the clipboard, replacing its content.
+///
+/// # arguments
+/// * `text`: Text to copy.
+pub fn set_text(text: &str) -> Result<(), NulError> {
+    let text = CString::new(text)?;
+    Ok(unsafe { uiClipboardSetText(text.as_ptr()) })
+}
+
+/// Returns the text on the clipboard.
+///
+/// # returns
+/// * The clipboard text, `None` if the clipboard holds no text.
+pub fn get_text() -> Result<Option<String>, Utf8Error> {
+    let ptr = unsafe { uiClipboardText() };
+    if ptr.is_null() {
+        return Ok(None);
+    }
+
+    let text = unsafe { CStr::from_ptr(ptr) }.to_str().map(String::from);
+    unsafe { uiFreeText(ptr) };
+    Ok(Some(text?))
+}
+
+/// Places an image on the clipboard, replacing its content.
+///
+/// # arguments
+/// * `image`: The image. Its largest representation is copied.
+pub fn set_image(image: &Image) {
+    unsafe { uiClipboardSetImage(image.as_ptr_mut()) }
+}
+
+/// Returns the image on the clipboard.
+///
+/// # returns
+/// * The clipboard image with a single representation, `None` if the clipboard holds no image.
+pub fn get_image() -> Option<Image> {
+    let ptr = unsafe { uiClipboardImage() };
+    if ptr.is_null() {
+        return None;
+    }
+
+    Some(Image::from_ptr(ptr))
+}
+
+unsafe extern "C" fn on_changed_(_data: *mut c_void) {
+    // The lock is released while calling so the callback can replace or clear itself.
+    let f = match CHANGED.lock() {
+        Ok(f) => f.clone(),
+        Err(e) => {
+            error!("An error was occurred in on_changed: {}", e);
+            return;
+        }
+    };
+    if let Some(f) = f {
+        match f.try_lock() {
+            Ok(mut f) => f(),
+            Err(e) => error!("An error was occurred in on_changed: {}", e),
+        }
+    }
+}
+
+/// Registers a callback for when the content of the clipboard changes.
+///
+/// # arguments
+/// * `f`: Callback function.
+///
+/// # note
+/// * Only one callback can be registered at a time.
+pub fn on_changed<F>(f: F) -> Result<(), UiError>
+where
+    F: FnMut() + Send + 'static,
+{
+    *CHANGED.lock()? = Some(Arc::new(Mutex::new(f)));
+    Ok(unsafe { uiClipboardOnChanged(Some(on_changed_), null_mut()) })
+}
+
+/// Unregisters a callback for when the content of the clipboard changes.
+pub fn clear_changed() -> Result<(), UiError> {
+    *CHANGED.lock()? = None;
+    Ok(unsafe { uiClipboardOnChanged(None, null_mut()) })
+}
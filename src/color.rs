@@ -0,0 +1,30 @@
+/// A color with red, green, blue and alpha components in the range `[0, 1.0]`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Color {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+impl Color {
+    /// Creates a new color.
+    ///
+    /// # arguments
+    /// * `r`: Red. Double in range of `[0, 1.0]`.
+    /// * `g`: Green. Double in range of `[0, 1.0]`.
+    /// * `b`: Blue. Double in range of `[0, 1.0]`.
+    /// * `a`: Alpha. Double in range of `[0, 1.0]`.
+    pub fn new(r: f64, g: f64, b: f64, a: f64) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Creates an opaque color from a `0xRRGGBB` value.
+    ///
+    /// # arguments
+    /// * `rgb`: Red, green and blue as bytes, e.g. `0xff8000` for orange.
+    pub fn rgb(rgb: u32) -> Self {
+        let channel = |shift: u32| ((rgb >> shift) & 0xff) as f64 / 255.;
+        Self::new(channel(16), channel(8), channel(0), 1.)
+    }
+}
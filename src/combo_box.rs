@@ -30,6 +30,8 @@ impl AsRef<Self> for ComboBox {
 }
 
 impl Control for ComboBox {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x436F6D62);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
@@ -10,8 +10,26 @@ use {
         ptr::null_mut,
     },
 };
+#[cfg(target_os = "linux")]
+use {
+    crate::{
+        DropData, PopupMenu,
+        drag_drop::{set_on_drag_hover, set_on_drop},
+        error::UiError,
+        focus::{set_on_focus_in, set_on_focus_out},
+        raw::{uiControlFocus, uiControlHasFocus, uiControlSetContextMenu, uiControlSetTooltip},
+    },
+    std::{
+        ptr::null,
+        sync::{Arc, Mutex},
+    },
+};
 
 pub trait Control: AsRef<Self> {
+    /// The libui type signature of the control, used to check the type of controls returned by
+    /// libui. `None` if the type is not backed by a single libui control type.
+    const TYPE_SIGNATURE: Option<u32> = None;
+
     fn as_ptr(&self) -> *const uiControl {
         self.as_ptr_mut() as _
     }
@@ -163,6 +181,171 @@ pub trait Control: AsRef<Self> {
         unsafe { uiControlDisable(self.as_ptr_mut()) }
     }
 
+    /// Sets the text shown when the mouse pointer rests on the control.
+    ///
+    /// # arguments
+    /// * `tooltip`: Tooltip text, `None` to remove the tooltip.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    fn set_tooltip(&self, tooltip: Option<&str>) -> Result<(), NulError> {
+        let tooltip = tooltip.map(CString::new).transpose()?;
+        let ptr = match &tooltip {
+            None => null(),
+            Some(t) => t.as_ptr(),
+        };
+
+        Ok(unsafe { uiControlSetTooltip(self.as_ptr_mut(), ptr) })
+    }
+
+    /// Gives keyboard focus to the control.
+    ///
+    /// # note
+    /// * Controls that cannot take focus themselves focus their first focusable child.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    fn focus(&self) {
+        unsafe { uiControlFocus(self.as_ptr_mut()) }
+    }
+
+    /// Returns whether the control or one of its children is the focus widget of its window.
+    ///
+    /// # returns
+    /// * `true` if the control has focus, `false` otherwise.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    fn has_focus(&self) -> bool {
+        unsafe { uiControlHasFocus(self.as_ptr_mut()) != 0 }
+    }
+
+    /// Registers a callback for when the control gains keyboard focus.
+    ///
+    /// # arguments
+    /// * `f`: Callback function.
+    ///
+    /// # note
+    /// * Only one callback can be registered at a time.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    fn on_focus_in<F>(&self, f: F) -> Result<(), UiError>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        set_on_focus_in(self.as_ptr_mut(), Some(Arc::new(Mutex::new(f))))
+    }
+
+    /// Unregisters a callback for when the control gains keyboard focus.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    fn clear_focus_in(&self) -> Result<(), UiError> {
+        set_on_focus_in(self.as_ptr_mut(), None)
+    }
+
+    /// Registers a callback for when the control loses keyboard focus.
+    ///
+    /// # arguments
+    /// * `f`: Callback function.
+    ///
+    /// # note
+    /// * Only one callback can be registered at a time.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    fn on_focus_out<F>(&self, f: F) -> Result<(), UiError>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        set_on_focus_out(self.as_ptr_mut(), Some(Arc::new(Mutex::new(f))))
+    }
+
+    /// Unregisters a callback for when the control loses keyboard focus.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    fn clear_focus_out(&self) -> Result<(), UiError> {
+        set_on_focus_out(self.as_ptr_mut(), None)
+    }
+
+    /// Sets the popup menu shown when the control is right-clicked.
+    /// The menu is also shown when the context menu key is pressed while the control has focus.
+    ///
+    /// # arguments
+    /// * `menu`: The popup menu, `None` to remove the context menu.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    fn set_context_menu(&self, menu: Option<&PopupMenu>) {
+        let menu = match menu {
+            None => null_mut(),
+            Some(m) => m.as_ptr_mut() as _,
+        };
+
+        unsafe { uiControlSetContextMenu(self.as_ptr_mut(), menu) }
+    }
+
+    /// Registers a callback for when files or text are dropped onto the control.
+    /// Supported by `Window`, `MultiLineEntry`, areas and tables.
+    ///
+    /// # arguments
+    /// * `f`: Callback function.
+    ///          @p data The dropped data.
+    ///          Return:
+    ///          `true` if the drop was accepted, `false` otherwise.
+    ///
+    /// # note
+    /// * Only one callback can be registered at a time.
+    /// * While a callback is registered, the control's own handling of drops is disabled.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    fn on_drop<F>(&self, f: F) -> Result<(), UiError>
+    where
+        F: FnMut(DropData) -> bool + Send + 'static,
+    {
+        set_on_drop(self.as_ptr_mut(), Some(Arc::new(Mutex::new(f))))
+    }
+
+    /// Unregisters a callback for when files or text are dropped onto the control.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    fn clear_drop(&self) -> Result<(), UiError> {
+        set_on_drop(self.as_ptr_mut(), None)
+    }
+
+    /// Registers a callback for when a drag with droppable data enters or leaves the control.
+    ///
+    /// # arguments
+    /// * `f`: Callback function.
+    ///          @p entered `true` if the drag entered the control, `false` if it left.
+    ///
+    /// # note
+    /// * Only one callback can be registered at a time.
+    /// * Only drags over controls with a callback registered via `on_drop()` are reported.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    fn on_drag_hover<F>(&self, f: F) -> Result<(), UiError>
+    where
+        F: FnMut(bool) + Send + 'static,
+    {
+        set_on_drag_hover(self.as_ptr_mut(), Some(Arc::new(Mutex::new(f))))
+    }
+
+    /// Unregisters a callback for when a drag enters or leaves the control.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    fn clear_drag_hover(&self) -> Result<(), UiError> {
+        set_on_drag_hover(self.as_ptr_mut(), None)
+    }
+
     /// Allocates a uiControl.
     /// Helper to allocate new controls.
     ///
@@ -11,116 +11,460 @@ use {
     log::error,
     std::{
         collections::HashMap,
-        ffi::c_void,
+        ffi::{c_int, c_void},
         mem::transmute,
+        ptr::copy_nonoverlapping,
         sync::Mutex,
-        time::{Duration, SystemTime, SystemTimeError},
+        time::{Duration, SystemTime},
     },
 };
 
 unsafe extern "C" {
-    fn mktime(time: *mut tm) -> u64;
-    fn localtime(timer: *const u64) -> *mut tm;
+    fn mktime(time: *mut Tm) -> i64;
+    fn localtime(timer: *const i64) -> *const c_int;
 }
 
-const TM_SIZE: usize = 28;
+/// Number of `int` members `struct tm` is guaranteed to start with.
+const TM_FIELDS: usize = 9;
 
-pub struct DateTimePicker {
-    _inner: *mut uiDateTimePicker,
+/// Mirror of `struct tm`.
+/// The trailing padding leaves room for platform specific members such as `tm_gmtoff` and `tm_zone`.
+#[repr(C)]
+#[derive(Clone, Copy, Default)]
+struct Tm {
+    tm_sec: c_int,
+    tm_min: c_int,
+    tm_hour: c_int,
+    tm_mday: c_int,
+    tm_mon: c_int,
+    tm_year: c_int,
+    tm_wday: c_int,
+    tm_yday: c_int,
+    tm_isdst: c_int,
+    _reserved: [usize; 3],
 }
 
-impl AsRef<Self> for DateTimePicker {
-    fn as_ref(&self) -> &Self {
-        self
+/// A calendar date as displayed by a [`DatePicker`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Date {
+    /// Full year, e.g. `2025`.
+    pub year: i32,
+    /// Month of the year in the range `[1, 12]`.
+    pub month: u32,
+    /// Day of the month in the range `[1, 31]`.
+    pub day: u32,
+}
+
+impl Date {
+    /// Creates a new date.
+    ///
+    /// # arguments
+    /// * `year`: Full year, e.g. `2025`.
+    /// * `month`: Month of the year in the range `[1, 12]`.
+    /// * `day`: Day of the month in the range `[1, 31]`.
+    pub fn new(year: i32, month: u32, day: u32) -> Self {
+        Self { year, month, day }
     }
 }
 
-impl Control for DateTimePicker {
-    fn as_ptr_mut(&self) -> *mut uiControl {
-        self._inner as _
+/// A time of day as displayed by a [`TimePicker`].
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Time {
+    /// Hour in the range `[0, 23]`.
+    pub hour: u32,
+    /// Minute in the range `[0, 59]`.
+    pub minute: u32,
+    /// Second in the range `[0, 59]`.
+    pub second: u32,
+}
+
+impl Time {
+    /// Creates a new time of day.
+    ///
+    /// # arguments
+    /// * `hour`: Hour in the range `[0, 23]`.
+    /// * `minute`: Minute in the range `[0, 59]`.
+    /// * `second`: Second in the range `[0, 59]`.
+    pub fn new(hour: u32, minute: u32, second: u32) -> Self {
+        Self {
+            hour,
+            minute,
+            second,
+        }
     }
+}
+
+struct Bounds<V> {
+    min: Option<V>,
+    max: Option<V>,
+    observed: bool,
+}
 
-    fn from_ptr(ptr: *mut uiControl) -> Self {
-        Self { _inner: ptr as _ }
+impl<V> Default for Bounds<V> {
+    fn default() -> Self {
+        Self {
+            min: None,
+            max: None,
+            observed: false,
+        }
     }
 }
 
-impl DateTimePicker {
-    /// Returns date and time stored in the data time picker.
+type BoundsMap<V> = Mutex<Option<HashMap<isize, Bounds<V>>>>;
+
+trait PickerValue: Copy + PartialOrd + Send + 'static {
+    fn from_tm(t: &Tm) -> Self;
+
+    fn apply(&self, t: &mut Tm);
+
+    fn bounds() -> &'static BoundsMap<Self>;
+}
+
+impl PickerValue for Date {
+    fn from_tm(t: &Tm) -> Self {
+        Self::new(t.tm_year + 1900, (t.tm_mon + 1) as _, t.tm_mday as _)
+    }
+
+    fn apply(&self, t: &mut Tm) {
+        t.tm_year = self.year - 1900;
+        t.tm_mon = self.month as c_int - 1;
+        t.tm_mday = self.day as _;
+        t.tm_isdst = -1;
+    }
+
+    fn bounds() -> &'static BoundsMap<Self> {
+        static BOUNDS: BoundsMap<Date> = Mutex::new(None);
+        &BOUNDS
+    }
+}
+
+impl PickerValue for Time {
+    fn from_tm(t: &Tm) -> Self {
+        Self::new(t.tm_hour as _, t.tm_min as _, t.tm_sec as _)
+    }
+
+    fn apply(&self, t: &mut Tm) {
+        t.tm_hour = self.hour as _;
+        t.tm_min = self.minute as _;
+        t.tm_sec = self.second as _;
+        t.tm_isdst = -1;
+    }
+
+    fn bounds() -> &'static BoundsMap<Self> {
+        static BOUNDS: BoundsMap<Time> = Mutex::new(None);
+        &BOUNDS
+    }
+}
+
+impl PickerValue for SystemTime {
+    fn from_tm(t: &Tm) -> Self {
+        let mut t = *t;
+        let ts = unsafe { mktime(&mut t) };
+        if ts < 0 {
+            SystemTime::UNIX_EPOCH - Duration::from_secs(ts.unsigned_abs())
+        } else {
+            SystemTime::UNIX_EPOCH + Duration::from_secs(ts as _)
+        }
+    }
+
+    fn apply(&self, t: &mut Tm) {
+        let ts = match self.duration_since(SystemTime::UNIX_EPOCH) {
+            Ok(d) => d.as_secs() as i64,
+            Err(e) => -(e.duration().as_secs() as i64),
+        };
+        let ptr = unsafe { localtime(&ts) };
+        if !ptr.is_null() {
+            unsafe { copy_nonoverlapping(ptr, t as *mut Tm as *mut c_int, TM_FIELDS) }
+        }
+    }
+
+    fn bounds() -> &'static BoundsMap<Self> {
+        static BOUNDS: BoundsMap<SystemTime> = Mutex::new(None);
+        &BOUNDS
+    }
+}
+
+fn raw_value(ptr: *mut uiDateTimePicker) -> Tm {
+    let mut t = Tm::default();
+    unsafe { uiDateTimePickerTime(ptr, &mut t as *mut Tm as *mut tm) };
+    t
+}
+
+fn set_raw_value(ptr: *mut uiDateTimePicker, t: &Tm) {
+    unsafe { uiDateTimePickerSetTime(ptr, t as *const Tm as *const tm) }
+}
+
+fn with_bounds<V, R, F>(ptr: *mut uiDateTimePicker, f: F) -> Result<R, UiError>
+where
+    V: PickerValue,
+    F: FnOnce(&mut Bounds<V>) -> R,
+{
+    let mut lock = V::bounds().lock()?;
+    let bounds = lock
+        .get_or_insert_with(Default::default)
+        .entry(ptr as _)
+        .or_default();
+    Ok(f(bounds))
+}
+
+fn clamp<V>(ptr: *mut uiDateTimePicker) -> Result<(), UiError>
+where
+    V: PickerValue,
+{
+    let (min, max) = with_bounds::<V, _, _>(ptr, |b| (b.min, b.max))?;
+    let mut t = raw_value(ptr);
+    let value = V::from_tm(&t);
+    let bound = match (min, max) {
+        (Some(min), _) if value < min => min,
+        (_, Some(max)) if value > max => max,
+        _ => return Ok(()),
+    };
+    bound.apply(&mut t);
+    set_raw_value(ptr, &t);
+    Ok(())
+}
+
+fn set_value<V>(ptr: *mut uiDateTimePicker, value: V) -> Result<(), UiError>
+where
+    V: PickerValue,
+{
+    let mut t = raw_value(ptr);
+    value.apply(&mut t);
+    set_raw_value(ptr, &t);
+    clamp::<V>(ptr)
+}
+
+macro_rules! define_picker {
+    ($(#[$attr:meta])* $name:ident, $value:ty, $new:ident, $kind:literal) => {
+        $(#[$attr])*
+        pub struct $name {
+            _inner: *mut uiDateTimePicker,
+        }
+
+        impl AsRef<Self> for $name {
+            fn as_ref(&self) -> &Self {
+                self
+            }
+        }
+
+        impl Control for $name {
+            const TYPE_SIGNATURE: Option<u32> = Some(0x44545069);
+
+            fn as_ptr_mut(&self) -> *mut uiControl {
+                self._inner as _
+            }
+
+            fn from_ptr(ptr: *mut uiControl) -> Self {
+                Self { _inner: ptr as _ }
+            }
+        }
+
+        impl $name {
+            define_callback_function!(_on_changed, uiDateTimePickerOnChanged, (), uiDateTimePicker);
+            #[doc = concat!("Registers a callback for when the ", $kind, " picker value is changed by the user.")]
+            ///
+            /// # arguments
+            /// * `f`: Callback function.
+            ///          @p sender Back reference to the instance that triggered the callback.
+            ///          @p senderData User data registered with the sender instance.
+            /// * `data`: User data to be passed to the callback.
+            ///
+            /// # note
+            /// * The value is clamped to the minimum and maximum before the callback is invoked.
+            /// * The callback is not triggered when setting the value programmatically.
+            /// * Only one callback can be registered at a time.
+            pub fn on_changed<'a, 'b, F, T>(&self, mut f: F, data: &'a mut T) -> Result<(), UiError>
+            where
+                T: Copy + 'b,
+                F: FnMut(Self, &'b mut T) + Send + 'static,
+                'b: 'a,
+            {
+                with_bounds::<$value, _, _>(self._inner, |b| b.observed = true)?;
+                self._on_changed(
+                    Some(move |p: Self, d| {
+                        if let Err(e) = clamp::<$value>(p._inner) {
+                            error!("Can't clamp the {} picker value: {}", $kind, e);
+                        }
+                        f(p, d)
+                    }),
+                    data,
+                )
+            }
+
+            #[doc = concat!("Unregisters a callback for when the ", $kind, " picker value is changed by the user.")]
+            ///
+            /// # note
+            /// * The minimum and maximum keep being enforced.
+            pub fn clear_changed(&self) -> Result<(), UiError> {
+                with_bounds::<$value, _, _>(self._inner, |b| b.observed = false)?;
+                self.watch_bounds()
+            }
+
+            /// Returns the smallest value the user is allowed to pick.
+            ///
+            /// # returns
+            /// * The minimum, `None` if unbounded. [Default: `None`]
+            pub fn minimum(&self) -> Result<Option<$value>, UiError> {
+                with_bounds::<$value, _, _>(self._inner, |b| b.min)
+            }
+
+            /// Sets the smallest value the user is allowed to pick.
+            /// Earlier values are clamped to the minimum whenever the user changes the value.
+            ///
+            /// # arguments
+            /// * `min`: The minimum, `None` to remove the bound.
+            ///
+            /// # note
+            /// * The current value is clamped immediately.
+            pub fn set_minimum(&self, min: Option<$value>) -> Result<(), UiError> {
+                with_bounds::<$value, _, _>(self._inner, |b| b.min = min)?;
+                clamp::<$value>(self._inner)?;
+                self.watch_bounds()
+            }
+
+            /// Returns the largest value the user is allowed to pick.
+            ///
+            /// # returns
+            /// * The maximum, `None` if unbounded. [Default: `None`]
+            pub fn maximum(&self) -> Result<Option<$value>, UiError> {
+                with_bounds::<$value, _, _>(self._inner, |b| b.max)
+            }
+
+            /// Sets the largest value the user is allowed to pick.
+            /// Later values are clamped to the maximum whenever the user changes the value.
+            ///
+            /// # arguments
+            /// * `max`: The maximum, `None` to remove the bound.
+            ///
+            /// # note
+            /// * The current value is clamped immediately.
+            pub fn set_maximum(&self, max: Option<$value>) -> Result<(), UiError> {
+                with_bounds::<$value, _, _>(self._inner, |b| b.max = max)?;
+                clamp::<$value>(self._inner)?;
+                self.watch_bounds()
+            }
+
+            /// Makes sure the bounds are enforced when no user callback is registered.
+            fn watch_bounds(&self) -> Result<(), UiError> {
+                let (bounded, observed) = with_bounds::<$value, _, _>(self._inner, |b| {
+                    (b.min.is_some() || b.max.is_some(), b.observed)
+                })?;
+                if observed {
+                    return Ok(());
+                }
+
+                if bounded {
+                    self._on_changed(
+                        Some(|p: Self, _| {
+                            if let Err(e) = clamp::<$value>(p._inner) {
+                                error!("Can't clamp the {} picker value: {}", $kind, e);
+                            }
+                        }),
+                        &mut (),
+                    )
+                } else {
+                    #[allow(unused_assignments)]
+                    let mut func = Some(|_, _| ());
+                    func = None;
+                    self._on_changed(func, &mut ())
+                }
+            }
+
+            #[doc = concat!("Creates a new ", $kind, " picker.")]
+            ///
+            /// # returns
+            /// * A new uiDateTimePicker instance.
+            pub fn new() -> Self {
+                let ptr = unsafe { $new() };
+                Self { _inner: ptr }.into()
+            }
+        }
+    };
+}
+
+define_picker!(
+    /// A control to enter a date.
+    DatePicker,
+    Date,
+    uiNewDatePicker,
+    "date"
+);
+
+define_picker!(
+    /// A control to enter a time of day.
+    TimePicker,
+    Time,
+    uiNewTimePicker,
+    "time"
+);
+
+define_picker!(
+    /// A control to enter a date and time.
+    DateTimePicker,
+    SystemTime,
+    uiNewDateTimePicker,
+    "date time"
+);
+
+impl DatePicker {
+    /// Returns the date stored in the date picker.
     ///
     /// # returns
-    /// * Date and/or time as local time.
-    pub fn time(&self) -> SystemTime {
-        let mut t = [0u8; TM_SIZE];
-        let ptr = &mut t as *mut [u8; TM_SIZE] as *mut tm;
-        unsafe { uiDateTimePickerTime(self._inner, ptr) };
-        let ts = unsafe { mktime(ptr) };
-        SystemTime::UNIX_EPOCH + Duration::from_secs(ts)
+    /// * Date as local time.
+    pub fn date(&self) -> Date {
+        Date::from_tm(&raw_value(self._inner))
     }
 
-    /// Sets date and time of the data time picker.
+    /// Sets the date of the date picker.
     ///
     /// # arguments
-    /// * `time`: Date and/or time as local time.
-    pub fn set_time(&self, time: SystemTime) -> Result<(), SystemTimeError> {
-        let ts = time.duration_since(SystemTime::UNIX_EPOCH)?.as_secs();
-        let ptr = unsafe { localtime(&ts as *const _) };
-        Ok(unsafe { uiDateTimePickerSetTime(self._inner, ptr) })
+    /// * `date`: Date as local time.
+    ///
+    /// # note
+    /// * Dates out of range are clamped to the nearest bound.
+    pub fn set_date(&self, date: Date) -> Result<(), UiError> {
+        set_value(self._inner, date)
     }
+}
 
-    define_callback_function!(_on_changed, uiDateTimePickerOnChanged, (), uiDateTimePicker);
-    /// Registers a callback for when the date time picker value is changed by the user.
+impl TimePicker {
+    /// Returns the time of day stored in the time picker.
+    ///
+    /// # returns
+    /// * Time as local time.
+    pub fn time(&self) -> Time {
+        Time::from_tm(&raw_value(self._inner))
+    }
+
+    /// Sets the time of day of the time picker.
     ///
     /// # arguments
-    /// * `f`: Callback function.
-    ///          @p sender Back reference to the instance that triggered the callback.
-    ///          @p senderData User data registered with the sender instance.
-    /// * `data`: User data to be passed to the callback.
+    /// * `time`: Time as local time.
     ///
     /// # note
-    /// * The callback is not triggered when calling  uiDateTimePickerSetTime().
-    /// * Only one callback can be registered at a time.
-    pub fn on_changed<'a, 'b, F, T>(&self, f: F, data: &'a mut T) -> Result<(), UiError>
-    where
-        T: Copy + 'b,
-        F: FnMut(Self, &'b mut T) + Send + 'static,
-        'b: 'a,
-    {
-        self._on_changed(Some(f), data)
-    }
-
-    /// Unregisters a callback for when the date time picker value is changed by the user.
-    pub fn clear_changed(&self) -> Result<(), UiError> {
-        #[allow(unused_assignments)]
-        let mut func = Some(|_, _| ());
-        func = None;
-        self._on_changed(func, &mut ())
-    }
-
-    /// Creates a new date picker.
-    ///
-    /// # returns
-    /// * A new uiDateTimePicker instance.
-    pub fn new_date() -> Self {
-        let ptr = unsafe { uiNewDatePicker() };
-        Self { _inner: ptr }.into()
+    /// * Times out of range are clamped to the nearest bound.
+    pub fn set_time(&self, time: Time) -> Result<(), UiError> {
+        set_value(self._inner, time)
     }
+}
 
-    /// Creates a new time picker.
+impl DateTimePicker {
+    /// Returns date and time stored in the data time picker.
     ///
     /// # returns
-    /// * A new uiDateTimePicker instance.
-    pub fn new_time() -> Self {
-        let ptr = unsafe { uiNewTimePicker() };
-        Self { _inner: ptr }.into()
+    /// * Date and time as local time.
+    pub fn time(&self) -> SystemTime {
+        SystemTime::from_tm(&raw_value(self._inner))
     }
 
-    /// Creates a new date and time picker.
+    /// Sets date and time of the data time picker.
     ///
-    /// # returns
-    /// * A new uiDateTimePicker instance.
-    pub fn new() -> Self {
-        let ptr = unsafe { uiNewDateTimePicker() };
-        Self { _inner: ptr }.into()
+    /// # arguments
+    /// * `time`: Date and time as local time.
+    ///
+    /// # note
+    /// * Points in time out of range are clamped to the nearest bound.
+    pub fn set_time(&self, time: SystemTime) -> Result<(), UiError> {
+        set_value(self._inner, time)
     }
 }
@@ -0,0 +1,119 @@
+use {
+    crate::{
+        error::UiError,
+        file_dialog::path_from_c,
+        modify_callback,
+        raw::{uiControl, uiControlOnDragHover, uiControlOnDrop, uiDropData},
+    },
+    log::error,
+    std::{
+        collections::HashMap,
+        ffi::{CStr, c_int, c_void},
+        path::PathBuf,
+        ptr::null_mut,
+        slice::from_raw_parts,
+        sync::{Arc, Mutex},
+    },
+};
+
+/// Data dropped onto a control.
+///
+/// Only available on Linux for now.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DropData {
+    /// Dropped files and folders.
+    Paths(Vec<PathBuf>),
+    /// Dropped text.
+    Text(String),
+}
+
+impl DropData {
+    /// # safety
+    /// * `data` must point to a valid `uiDropData`.
+    unsafe fn from_raw(data: *const uiDropData) -> Option<Self> {
+        let data = unsafe { &*data };
+        if !data.Text.is_null() {
+            let text = unsafe { CStr::from_ptr(data.Text) };
+            return Some(Self::Text(text.to_string_lossy().into_owned()));
+        }
+        if data.Paths.is_null() {
+            return None;
+        }
+
+        let paths = unsafe { from_raw_parts(data.Paths, data.NumPaths) };
+        Some(Self::Paths(
+            paths.iter().map(|p| unsafe { path_from_c(*p) }).collect(),
+        ))
+    }
+}
+
+type OnDrop = Arc<Mutex<dyn FnMut(DropData) -> bool + Send>>;
+type OnDragHover = Arc<Mutex<dyn FnMut(bool) + Send>>;
+
+static ON_DROP: Mutex<Option<HashMap<isize, OnDrop>>> = Mutex::new(None);
+static ON_DRAG_HOVER: Mutex<Option<HashMap<isize, OnDragHover>>> = Mutex::new(None);
+
+unsafe extern "C" fn on_drop_(c: *mut uiControl, data: *const uiDropData, _: *mut c_void) -> c_int {
+    let Some(data) = (unsafe { DropData::from_raw(data) }) else {
+        return 0;
+    };
+    // The map is not kept locked, so the callback can change callbacks or run a nested loop.
+    let f = match modify_callback!(ON_DROP, m, { m.get(&(c as _)).cloned() }) {
+        Ok(Some(f)) => f,
+        Ok(None) => return 0,
+        Err(e) => {
+            error!("An error was occurred in on_drop: {}", e);
+            return 0;
+        }
+    };
+    match f.try_lock() {
+        Ok(mut f) => f(data) as _,
+        Err(e) => {
+            error!("An error was occurred in on_drop: {}", e);
+            0
+        }
+    }
+}
+
+unsafe extern "C" fn on_drag_hover_(c: *mut uiControl, entered: c_int, _: *mut c_void) {
+    let f = match modify_callback!(ON_DRAG_HOVER, m, { m.get(&(c as _)).cloned() }) {
+        Ok(Some(f)) => f,
+        Ok(None) => return,
+        Err(e) => {
+            error!("An error was occurred in on_drag_hover: {}", e);
+            return;
+        }
+    };
+    match f.try_lock() {
+        Ok(mut f) => f(entered != 0),
+        Err(e) => error!("An error was occurred in on_drag_hover: {}", e),
+    }
+}
+
+pub(crate) fn set_on_drop(c: *mut uiControl, f: Option<OnDrop>) -> Result<(), UiError> {
+    let cb = match f {
+        Some(f) => {
+            modify_callback!(ON_DROP, m, { m.insert(c as _, f) })?;
+            Some(on_drop_ as _)
+        }
+        None => {
+            modify_callback!(ON_DROP, m, { m.remove(&(c as _)) })?;
+            None
+        }
+    };
+    Ok(unsafe { uiControlOnDrop(c, cb, null_mut()) })
+}
+
+pub(crate) fn set_on_drag_hover(c: *mut uiControl, f: Option<OnDragHover>) -> Result<(), UiError> {
+    let cb = match f {
+        Some(f) => {
+            modify_callback!(ON_DRAG_HOVER, m, { m.insert(c as _, f) })?;
+            Some(on_drag_hover_ as _)
+        }
+        None => {
+            modify_callback!(ON_DRAG_HOVER, m, { m.remove(&(c as _)) })?;
+            None
+        }
+    };
+    Ok(unsafe { uiControlOnDragHover(c, cb, null_mut()) })
+}
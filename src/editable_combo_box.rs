@@ -29,6 +29,8 @@ impl AsRef<Self> for EditableCombobox {
 }
 
 impl Control for EditableCombobox {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x45644362);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
@@ -1,3 +1,12 @@
+#[cfg(target_os = "linux")]
+use {
+    crate::raw::{
+        uiEntryCursorPosition, uiEntryMaxLength, uiEntryOnActivate, uiEntrySelectRange,
+        uiEntrySelection, uiEntrySetMaxLength, uiEntrySetPlaceholder, uiEntrySetVisibility,
+        uiEntryVisibility,
+    },
+    std::ptr::null,
+};
 use {
     crate::{
         Control, define_callback_function,
@@ -30,6 +39,8 @@ impl AsRef<Self> for Entry {
 }
 
 impl Control for Entry {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x456E7472);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
@@ -104,6 +115,152 @@ impl Entry {
         unsafe { uiEntrySetReadOnly(self._inner, readonly as _) }
     }
 
+    #[cfg(target_os = "linux")]
+    define_callback_function!(_on_activate, uiEntryOnActivate, (), uiEntry);
+    /// Registers a callback for when the user presses Enter in the entry.
+    ///
+    /// # arguments
+    /// * `f`: Callback function.
+    ///          @p sender Back reference to the instance that triggered the callback.
+    ///          @p senderData User data registered with the sender instance.
+    /// * `data`: User data to be passed to the callback.
+    ///
+    /// # note
+    /// * Only one callback can be registered at a time.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn on_activate<'a, 'b, F, T>(&self, f: F, data: &'a mut T) -> Result<(), UiError>
+    where
+        T: Copy + 'b,
+        F: FnMut(Self, &'b mut T) + Send + 'static,
+        'b: 'a,
+    {
+        self._on_activate(Some(f), data)
+    }
+
+    /// Unregisters a callback for when the user presses Enter in the entry.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn clear_activate(&self) -> Result<(), UiError> {
+        #[allow(unused_assignments)]
+        let mut func = Some(|_, _| ());
+        func = None;
+        self._on_activate(func, &mut ())
+    }
+
+    /// Sets the text shown in the empty entry as a hint for the expected input.
+    ///
+    /// # arguments
+    /// * `text`: Placeholder text, `None` to remove the placeholder.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_placeholder(&self, text: Option<&str>) -> Result<(), NulError> {
+        let text = text.map(CString::new).transpose()?;
+        let ptr = match &text {
+            None => null(),
+            Some(t) => t.as_ptr(),
+        };
+
+        Ok(unsafe { uiEntrySetPlaceholder(self._inner, ptr) })
+    }
+
+    /// Returns the maximum number of characters the entry accepts.
+    ///
+    /// # returns
+    /// * Maximum number of characters, `0` if unlimited.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn max_length(&self) -> i32 {
+        unsafe { uiEntryMaxLength(self._inner) }
+    }
+
+    /// Sets the maximum number of characters the entry accepts.
+    /// Text already exceeding the limit is truncated.
+    ///
+    /// # arguments
+    /// * `length`: Maximum number of characters, `0` for unlimited.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_max_length(&self, length: i32) {
+        unsafe { uiEntrySetMaxLength(self._inner, length) }
+    }
+
+    /// Returns whether the entry's text is shown or masked.
+    ///
+    /// # returns
+    /// * `true` if the text is shown, `false` if masked.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn visibility(&self) -> bool {
+        unsafe { uiEntryVisibility(self._inner) != 0 }
+    }
+
+    /// Sets whether the entry's text is shown or masked, e.g. to reveal a password.
+    ///
+    /// # arguments
+    /// * `visible`: `true` to show the text, `false` to mask it.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_visibility(&self, visible: bool) {
+        unsafe { uiEntrySetVisibility(self._inner, visible as _) }
+    }
+
+    /// Selects a range of the entry's text.
+    ///
+    /// # arguments
+    /// * `start`: Character index where the selection starts.
+    /// * `end`: Character index where the selection ends, `-1` for the end of the text.
+    ///
+    /// # note
+    /// * Passing the same index for `start` and `end` clears the selection.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn select_range(&self, start: i32, end: i32) {
+        unsafe { uiEntrySelectRange(self._inner, start, end) }
+    }
+
+    /// Returns the selected range of the entry's text.
+    ///
+    /// # returns
+    /// * The character indices `(start, end)` of the selection, `None` if no text is selected.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn selection(&self) -> Option<(i32, i32)> {
+        let (mut start, mut end) = (0, 0);
+        let selected = unsafe { uiEntrySelection(self._inner, &mut start, &mut end) };
+        if selected == 0 {
+            None
+        } else {
+            Some((start, end))
+        }
+    }
+
+    /// Returns the position of the entry's text cursor.
+    ///
+    /// # returns
+    /// * Character index of the cursor.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn cursor_position(&self) -> i32 {
+        unsafe { uiEntryCursorPosition(self._inner) }
+    }
+
     /// Creates a new entry.
     ///
     /// # returns
@@ -1,13 +1,24 @@
-use std::{
-    error::Error,
-    fmt::{Display, Formatter, Result as FmtResult},
-    str::Utf8Error,
-    sync::PoisonError,
+use {
+    log::SetLoggerError,
+    std::{
+        error::Error,
+        ffi::NulError,
+        fmt::{Display, Formatter, Result as FmtResult},
+        io::Error as IoError,
+        str::Utf8Error,
+        sync::PoisonError,
+    },
 };
 
 #[derive(Debug)]
 pub enum UiError {
+    Accelerator(String),
+    Dialog(String),
     Init(String),
+    Io(IoError),
+    Logger(SetLoggerError),
+    Menu(String),
+    Nul(NulError),
     Poison(String),
     Utf8(Utf8Error),
 }
@@ -16,7 +27,13 @@ impl Display for UiError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         write!(f, "UiError: ")?;
         match self {
+            Self::Accelerator(e) => write!(f, "Invalid accelerator: {}", e),
+            Self::Dialog(e) => write!(f, "DialogError: {}", e),
             Self::Init(e) => write!(f, "InitError: {}", e),
+            Self::Io(e) => Display::fmt(e, f),
+            Self::Logger(e) => Display::fmt(e, f),
+            Self::Menu(e) => write!(f, "MenuError: {}", e),
+            Self::Nul(e) => Display::fmt(e, f),
             Self::Poison(e) => Display::fmt(e, f),
             Self::Utf8(e) => Display::fmt(e, f),
         }
@@ -31,8 +48,26 @@ impl From<Utf8Error> for UiError {
     }
 }
 
+impl From<IoError> for UiError {
+    fn from(value: IoError) -> Self {
+        Self::Io(value)
+    }
+}
+
+impl From<NulError> for UiError {
+    fn from(value: NulError) -> Self {
+        Self::Nul(value)
+    }
+}
+
 impl<T> From<PoisonError<T>> for UiError {
     fn from(value: PoisonError<T>) -> Self {
         Self::Poison(value.to_string())
     }
 }
+
+impl From<SetLoggerError> for UiError {
+    fn from(value: SetLoggerError) -> Self {
+        Self::Logger(value)
+    }
+}
@@ -0,0 +1,268 @@
+use std::{
+    ffi::{CStr, c_char},
+    path::PathBuf,
+};
+#[cfg(target_os = "linux")]
+use {
+    crate::{
+        Control, Window,
+        error::UiError,
+        raw::{
+            uiFileDialog, uiFileDialogFilter, uiFileDialogMode, uiFileDialogModeOpenFile,
+            uiFileDialogModeOpenFolder, uiFileDialogModeSaveFile, uiFileDialogParams,
+            uiFreeFileDialogPaths,
+        },
+    },
+    std::{
+        ffi::{CString, NulError, OsStr, OsString},
+        path::Path,
+        ptr::null,
+    },
+};
+
+/// Converts a path to the encoding expected by the platform file dialogs.
+#[cfg(target_os = "linux")]
+pub(crate) fn path_to_c<P: AsRef<OsStr>>(path: P) -> Result<CString, NulError> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        CString::new(path.as_ref().as_bytes())
+    }
+    #[cfg(not(unix))]
+    {
+        CString::new(path.as_ref().to_string_lossy().as_bytes())
+    }
+}
+
+/// Converts a path returned by the platform file dialogs.
+///
+/// # safety
+/// * `ptr` must point to a valid, `NUL` terminated string.
+pub(crate) unsafe fn path_from_c(ptr: *const c_char) -> PathBuf {
+    let bytes = unsafe { CStr::from_ptr(ptr) }.to_bytes();
+    #[cfg(unix)]
+    {
+        use std::{ffi::OsString, os::unix::ffi::OsStringExt};
+        OsString::from_vec(bytes.into()).into()
+    }
+    #[cfg(not(unix))]
+    {
+        String::from_utf8_lossy(bytes).into_owned().into()
+    }
+}
+
+/// A named filename filter such as `Images: *.png;*.jpg`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FileFilter {
+    pub name: String,
+    pub patterns: Vec<String>,
+}
+
+impl FileFilter {
+    /// Creates a new filter.
+    ///
+    /// # arguments
+    /// * `name`: Filter name shown to the user.
+    /// * `patterns`: Glob patterns, e.g. `*.png`.
+    pub fn new(name: &str, patterns: &[&str]) -> Self {
+        Self {
+            name: name.into(),
+            patterns: patterns.iter().map(|p| (*p).into()).collect(),
+        }
+    }
+}
+
+impl From<&str> for FileFilter {
+    /// Parses `name: pattern;pattern`. Without a name the patterns are used as name.
+    fn from(value: &str) -> Self {
+        let (name, patterns) = value.split_once(':').unwrap_or((value, value));
+        let patterns = patterns
+            .split(';')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(Into::into)
+            .collect::<Vec<_>>();
+        Self {
+            name: name.trim().into(),
+            patterns: match patterns.is_empty() {
+                true => vec!["*".into()],
+                false => patterns,
+            },
+        }
+    }
+}
+
+/// Builder for file chooser dialog windows.
+/// Only available on Linux for now.
+#[cfg(target_os = "linux")]
+#[derive(Clone, Debug, Default)]
+pub struct FileDialog {
+    title: Option<String>,
+    directory: Option<PathBuf>,
+    file_name: Option<OsString>,
+    filters: Vec<FileFilter>,
+    show_hidden: bool,
+}
+
+#[cfg(target_os = "linux")]
+impl FileDialog {
+    /// Creates a dialog with default options.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Sets the dialog window title text.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Sets the folder initially shown.
+    pub fn directory<P: AsRef<Path>>(mut self, directory: P) -> Self {
+        self.directory = Some(directory.as_ref().into());
+        self
+    }
+
+    /// Sets the suggested file name when saving, or the initially selected file when opening.
+    pub fn file_name<S: AsRef<OsStr>>(mut self, file_name: S) -> Self {
+        self.file_name = Some(file_name.as_ref().into());
+        self
+    }
+
+    /// Adds a filename filter. The first filter is active initially.
+    ///
+    /// # arguments
+    /// * `filter`: The filter, e.g. `"Images: *.png;*.jpg"`.
+    pub fn filter<F: Into<FileFilter>>(mut self, filter: F) -> Self {
+        self.filters.push(filter.into());
+        self
+    }
+
+    /// Sets whether hidden files are shown. [Default: `false`]
+    pub fn show_hidden(mut self, show_hidden: bool) -> Self {
+        self.show_hidden = show_hidden;
+        self
+    }
+
+    fn run(
+        &self,
+        parent: &Window,
+        mode: uiFileDialogMode,
+        multiple: bool,
+    ) -> Result<Vec<PathBuf>, UiError> {
+        let title = self.title.as_deref().map(CString::new).transpose()?;
+        let directory = self.directory.as_ref().map(path_to_c).transpose()?;
+        let file_name = self.file_name.as_ref().map(path_to_c).transpose()?;
+        let names = self
+            .filters
+            .iter()
+            .map(|f| CString::new(f.name.as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let patterns = self
+            .filters
+            .iter()
+            .map(|f| {
+                f.patterns
+                    .iter()
+                    .map(|p| CString::new(p.as_str()))
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<_>>, _>>()?;
+        let pattern_ptrs = patterns
+            .iter()
+            .map(|p| p.iter().map(|p| p.as_ptr()).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let filters = names
+            .iter()
+            .zip(&pattern_ptrs)
+            .map(|(name, patterns)| uiFileDialogFilter {
+                Name: name.as_ptr(),
+                NumPatterns: patterns.len(),
+                Patterns: patterns.as_ptr() as _,
+            })
+            .collect::<Vec<_>>();
+        let params = uiFileDialogParams {
+            Title: title.as_ref().map_or(null(), |t| t.as_ptr()),
+            Directory: directory.as_ref().map_or(null(), |d| d.as_ptr()),
+            FileName: file_name.as_ref().map_or(null(), |f| f.as_ptr()),
+            NumFilters: filters.len(),
+            Filters: filters.as_ptr(),
+            MultipleSelection: multiple as _,
+            ShowHidden: self.show_hidden as _,
+        };
+
+        let ptr = unsafe { uiFileDialog(parent.as_ptr_mut() as _, mode, &params) };
+        if ptr.is_null() {
+            return Ok(Vec::new());
+        }
+
+        let mut paths = Vec::new();
+        let mut i = 0;
+        loop {
+            let path = unsafe { *ptr.add(i) };
+            if path.is_null() {
+                break;
+            }
+            paths.push(unsafe { path_from_c(path) });
+            i += 1;
+        }
+        unsafe { uiFreeFileDialogPaths(ptr) };
+        Ok(paths)
+    }
+
+    /// Shows the dialog to select a single file.
+    ///
+    /// # arguments
+    /// * `parent`: Parent window.
+    ///
+    /// # returns
+    /// * File path, `None` on cancel.
+    pub fn open_file(&self, parent: &Window) -> Result<Option<PathBuf>, UiError> {
+        Ok(self.run(parent, uiFileDialogModeOpenFile, false)?.pop())
+    }
+
+    /// Shows the dialog to select multiple files.
+    ///
+    /// # arguments
+    /// * `parent`: Parent window.
+    ///
+    /// # returns
+    /// * File paths, empty on cancel.
+    pub fn open_files(&self, parent: &Window) -> Result<Vec<PathBuf>, UiError> {
+        self.run(parent, uiFileDialogModeOpenFile, true)
+    }
+
+    /// Shows the dialog to select a single folder.
+    ///
+    /// # arguments
+    /// * `parent`: Parent window.
+    ///
+    /// # returns
+    /// * Folder path, `None` on cancel.
+    pub fn open_folder(&self, parent: &Window) -> Result<Option<PathBuf>, UiError> {
+        Ok(self.run(parent, uiFileDialogModeOpenFolder, false)?.pop())
+    }
+
+    /// Shows the dialog to select multiple folders.
+    ///
+    /// # arguments
+    /// * `parent`: Parent window.
+    ///
+    /// # returns
+    /// * Folder paths, empty on cancel.
+    pub fn open_folders(&self, parent: &Window) -> Result<Vec<PathBuf>, UiError> {
+        self.run(parent, uiFileDialogModeOpenFolder, true)
+    }
+
+    /// Shows the dialog to select a file to save to.
+    /// The user is asked to confirm overwriting existing files.
+    ///
+    /// # arguments
+    /// * `parent`: Parent window.
+    ///
+    /// # returns
+    /// * File path, `None` on cancel.
+    pub fn save_file(&self, parent: &Window) -> Result<Option<PathBuf>, UiError> {
+        Ok(self.run(parent, uiFileDialogModeSaveFile, false)?.pop())
+    }
+}
@@ -0,0 +1,72 @@
+use {
+    crate::{
+        error::UiError,
+        modify_callback,
+        raw::{uiControl, uiControlOnFocusIn, uiControlOnFocusOut},
+    },
+    log::error,
+    std::{
+        collections::HashMap,
+        ffi::c_void,
+        ptr::null_mut,
+        sync::{Arc, Mutex},
+    },
+};
+
+type OnFocus = Arc<Mutex<dyn FnMut() + Send>>;
+
+static ON_FOCUS_IN: Mutex<Option<HashMap<isize, OnFocus>>> = Mutex::new(None);
+static ON_FOCUS_OUT: Mutex<Option<HashMap<isize, OnFocus>>> = Mutex::new(None);
+
+fn call(callbacks: &Mutex<Option<HashMap<isize, OnFocus>>>, c: *mut uiControl, name: &str) {
+    // The map is not kept locked, so the callback can move the focus or change callbacks.
+    let f = match modify_callback!(callbacks, m, { m.get(&(c as _)).cloned() }) {
+        Ok(Some(f)) => f,
+        Ok(None) => return,
+        Err(e) => {
+            error!("An error was occurred in {}: {}", name, e);
+            return;
+        }
+    };
+    // A callback triggered again while it is still running is skipped.
+    match f.try_lock() {
+        Ok(mut f) => f(),
+        Err(e) => error!("An error was occurred in {}: {}", name, e),
+    }
+}
+
+unsafe extern "C" fn on_focus_in_(c: *mut uiControl, _: *mut c_void) {
+    call(&ON_FOCUS_IN, c, "on_focus_in")
+}
+
+unsafe extern "C" fn on_focus_out_(c: *mut uiControl, _: *mut c_void) {
+    call(&ON_FOCUS_OUT, c, "on_focus_out")
+}
+
+pub(crate) fn set_on_focus_in(c: *mut uiControl, f: Option<OnFocus>) -> Result<(), UiError> {
+    let cb = match f {
+        Some(f) => {
+            modify_callback!(ON_FOCUS_IN, m, { m.insert(c as _, f) })?;
+            Some(on_focus_in_ as _)
+        }
+        None => {
+            modify_callback!(ON_FOCUS_IN, m, { m.remove(&(c as _)) })?;
+            None
+        }
+    };
+    Ok(unsafe { uiControlOnFocusIn(c, cb, null_mut()) })
+}
+
+pub(crate) fn set_on_focus_out(c: *mut uiControl, f: Option<OnFocus>) -> Result<(), UiError> {
+    let cb = match f {
+        Some(f) => {
+            modify_callback!(ON_FOCUS_OUT, m, { m.insert(c as _, f) })?;
+            Some(on_focus_out_ as _)
+        }
+        None => {
+            modify_callback!(ON_FOCUS_OUT, m, { m.remove(&(c as _)) })?;
+            None
+        }
+    };
+    Ok(unsafe { uiControlOnFocusOut(c, cb, null_mut()) })
+}
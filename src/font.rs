@@ -0,0 +1,183 @@
+use crate::raw::{
+    uiTextWeightBold, uiTextWeightBook, uiTextWeightHeavy, uiTextWeightLight, uiTextWeightMedium,
+    uiTextWeightNormal, uiTextWeightSemiBold, uiTextWeightThin, uiTextWeightUltraBold,
+    uiTextWeightUltraHeavy, uiTextWeightUltraLight,
+};
+#[cfg(target_os = "linux")]
+use {
+    crate::raw::{
+        uiFontDescriptor, uiTextItalic, uiTextItalicItalic, uiTextItalicNormal,
+        uiTextItalicOblique, uiTextStretch, uiTextStretchCondensed, uiTextStretchExpanded,
+        uiTextStretchExtraCondensed, uiTextStretchExtraExpanded, uiTextStretchNormal,
+        uiTextStretchSemiCondensed, uiTextStretchSemiExpanded, uiTextStretchUltraCondensed,
+        uiTextStretchUltraExpanded,
+    },
+    std::ffi::{CString, NulError},
+};
+
+/// The weight of a font, in the range `[0, 1000]`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TextWeight(pub u32);
+
+impl TextWeight {
+    pub const THIN: Self = Self(uiTextWeightThin as _);
+    pub const ULTRA_LIGHT: Self = Self(uiTextWeightUltraLight as _);
+    pub const LIGHT: Self = Self(uiTextWeightLight as _);
+    pub const BOOK: Self = Self(uiTextWeightBook as _);
+    pub const NORMAL: Self = Self(uiTextWeightNormal as _);
+    pub const MEDIUM: Self = Self(uiTextWeightMedium as _);
+    pub const SEMI_BOLD: Self = Self(uiTextWeightSemiBold as _);
+    pub const BOLD: Self = Self(uiTextWeightBold as _);
+    pub const ULTRA_BOLD: Self = Self(uiTextWeightUltraBold as _);
+    pub const HEAVY: Self = Self(uiTextWeightHeavy as _);
+    pub const ULTRA_HEAVY: Self = Self(uiTextWeightUltraHeavy as _);
+}
+
+impl Default for TextWeight {
+    fn default() -> Self {
+        Self::NORMAL
+    }
+}
+
+/// The slant of a font.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum TextItalic {
+    #[default]
+    Normal,
+    Oblique,
+    Italic,
+}
+
+impl TextItalic {
+    #[cfg(target_os = "linux")]
+    const ALL: [Self; 3] = [Self::Normal, Self::Oblique, Self::Italic];
+
+    #[cfg(target_os = "linux")]
+    fn to_raw(self) -> uiTextItalic {
+        (match self {
+            Self::Normal => uiTextItalicNormal,
+            Self::Oblique => uiTextItalicOblique,
+            Self::Italic => uiTextItalicItalic,
+        }) as _
+    }
+}
+
+/// The width of a font.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum TextStretch {
+    UltraCondensed,
+    ExtraCondensed,
+    Condensed,
+    SemiCondensed,
+    #[default]
+    Normal,
+    SemiExpanded,
+    Expanded,
+    ExtraExpanded,
+    UltraExpanded,
+}
+
+impl TextStretch {
+    #[cfg(target_os = "linux")]
+    const ALL: [Self; 9] = [
+        Self::UltraCondensed,
+        Self::ExtraCondensed,
+        Self::Condensed,
+        Self::SemiCondensed,
+        Self::Normal,
+        Self::SemiExpanded,
+        Self::Expanded,
+        Self::ExtraExpanded,
+        Self::UltraExpanded,
+    ];
+
+    #[cfg(target_os = "linux")]
+    fn to_raw(self) -> uiTextStretch {
+        (match self {
+            Self::UltraCondensed => uiTextStretchUltraCondensed,
+            Self::ExtraCondensed => uiTextStretchExtraCondensed,
+            Self::Condensed => uiTextStretchCondensed,
+            Self::SemiCondensed => uiTextStretchSemiCondensed,
+            Self::Normal => uiTextStretchNormal,
+            Self::SemiExpanded => uiTextStretchSemiExpanded,
+            Self::Expanded => uiTextStretchExpanded,
+            Self::ExtraExpanded => uiTextStretchExtraExpanded,
+            Self::UltraExpanded => uiTextStretchUltraExpanded,
+        }) as _
+    }
+}
+
+/// A complete description of a font.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FontDescriptor {
+    /// Font family name, e.g. `"Sans"`.
+    pub family: String,
+    /// Font size in points.
+    pub size: f64,
+    pub weight: TextWeight,
+    pub italic: TextItalic,
+    pub stretch: TextStretch,
+}
+
+impl FontDescriptor {
+    /// Creates a new font description of normal weight, slant and width.
+    ///
+    /// # arguments
+    /// * `family`: Font family name, e.g. `"Sans"`.
+    /// * `size`: Font size in points.
+    pub fn new(family: &str, size: f64) -> Self {
+        Self {
+            family: family.into(),
+            size,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a new description of the system's monospace font.
+    ///
+    /// # arguments
+    /// * `size`: Font size in points.
+    pub fn monospace(size: f64) -> Self {
+        Self::new("Monospace", size)
+    }
+
+    /// Calls `f` with the raw representation of the font.
+    #[cfg(target_os = "linux")]
+    pub(crate) fn with_raw<R>(
+        &self,
+        f: impl FnOnce(&uiFontDescriptor) -> R,
+    ) -> Result<R, NulError> {
+        let family = CString::new(self.family.as_str())?;
+        let desc = uiFontDescriptor {
+            Family: family.as_ptr() as _,
+            Size: self.size,
+            Weight: self.weight.0 as _,
+            Italic: self.italic.to_raw(),
+            Stretch: self.stretch.to_raw(),
+        };
+        Ok(f(&desc))
+    }
+
+    /// Creates a font description from its raw representation.
+    ///
+    /// # safety
+    /// * `desc.Family` must point to a valid `NUL` terminated string.
+    #[cfg(target_os = "linux")]
+    pub(crate) unsafe fn from_raw(desc: &uiFontDescriptor) -> Self {
+        let italic = TextItalic::ALL
+            .into_iter()
+            .find(|i| i.to_raw() == desc.Italic);
+        let stretch = TextStretch::ALL
+            .into_iter()
+            .find(|s| s.to_raw() == desc.Stretch);
+        Self {
+            family: unsafe { std::ffi::CStr::from_ptr(desc.Family) }
+                .to_string_lossy()
+                .into_owned(),
+            size: desc.Size,
+            weight: TextWeight(desc.Weight as _),
+            italic: italic.unwrap_or_default(),
+            stretch: stretch.unwrap_or_default(),
+        }
+    }
+}
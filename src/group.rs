@@ -24,6 +24,8 @@ impl AsRef<Self> for Group {
 }
 
 impl Control for Group {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x47727062);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
@@ -0,0 +1,83 @@
+use {
+    crate::{
+        Control, queue_main,
+        raw::{uiControl, uiControlOnDestroyed},
+    },
+    std::{
+        ffi::c_void,
+        sync::{
+            Arc,
+            atomic::{AtomicBool, AtomicIsize, Ordering},
+        },
+    },
+};
+
+struct Inner {
+    // Raw pointer of the control, kept as an integer so the handle stays `Send`.
+    // `0` once the control was destroyed.
+    ptr: AtomicIsize,
+    queued: AtomicBool,
+}
+
+unsafe extern "C" fn on_destroyed(_: *mut uiControl, data: *mut c_void) {
+    let inner = unsafe { Arc::from_raw(data as *const Inner) };
+    inner.ptr.store(0, Ordering::SeqCst);
+}
+
+/// A reference to a control that can be kept in `Send` state and is cleared once the control
+/// was destroyed.
+///
+/// Cloned values refer to the same control.
+#[derive(Clone)]
+pub(crate) struct ControlHandle {
+    inner: Arc<Inner>,
+}
+
+impl ControlHandle {
+    /// Creates a new handle. Must be called on the main thread.
+    pub(crate) fn new<C: Control>(control: &C) -> Self {
+        let inner = Arc::new(Inner {
+            ptr: AtomicIsize::new(control.as_ptr_mut() as _),
+            queued: AtomicBool::new(false),
+        });
+        // The reference is released once the control was destroyed.
+        let data = Arc::into_raw(inner.clone());
+        unsafe { uiControlOnDestroyed(control.as_ptr_mut(), Some(on_destroyed), data as _) }
+        Self { inner }
+    }
+
+    /// Returns the control, `None` if it was destroyed. Must be called on the main thread.
+    pub(crate) fn get<C: Control>(&self) -> Option<C> {
+        match self.inner.ptr.load(Ordering::SeqCst) {
+            0 => None,
+            ptr => Some(C::from_ptr(ptr as _)),
+        }
+    }
+
+    /// Returns whether the control was not destroyed yet.
+    pub(crate) fn is_alive(&self) -> bool {
+        self.inner.ptr.load(Ordering::SeqCst) != 0
+    }
+
+    /// Runs `f` with the control on the main thread. Can be called from any thread.
+    ///
+    /// Nothing is queued while an earlier call waits for the main loop, so `f` has to show
+    /// every change made until it runs. Nothing runs once the control was destroyed.
+    pub(crate) fn queue<C, F>(&self, f: F)
+    where
+        C: Control,
+        F: FnOnce(C) + Send + 'static,
+    {
+        if !self.is_alive() || self.inner.queued.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let handle = self.clone();
+        queue_main(move || {
+            // Reset first, so changes made while `f` runs are queued again.
+            handle.inner.queued.store(false, Ordering::SeqCst);
+            if let Some(c) = handle.get::<C>() {
+                f(c)
+            }
+        });
+    }
+}
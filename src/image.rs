@@ -0,0 +1,70 @@
+use crate::raw::{uiFreeImage, uiImage, uiImageAppend, uiNewImage};
+
+/// A container for an image to be displayed on screen.
+///
+/// The container can hold multiple representations of the same image, each at a different
+/// pixel density. The system picks the representation best suited for the screen.
+/// Only premultiplied 32-bit RGBA images are supported.
+pub struct Image {
+    _inner: *mut uiImage,
+}
+
+impl Drop for Image {
+    fn drop(&mut self) {
+        unsafe { uiFreeImage(self._inner) }
+    }
+}
+
+impl Image {
+    #[cfg(target_os = "linux")]
+    pub(crate) fn as_ptr_mut(&self) -> *mut uiImage {
+        self._inner
+    }
+
+    #[cfg(target_os = "linux")]
+    pub(crate) fn from_ptr(ptr: *mut uiImage) -> Self {
+        Self { _inner: ptr }
+    }
+
+    /// Appends a new image representation.
+    ///
+    /// # arguments
+    /// * `pixels`: Premultiplied pixels in [R G B A] order.
+    /// * `pixel_width`: Width in pixels.
+    /// * `pixel_height`: Height in pixels.
+    /// * `byte_stride`: Number of bytes per row of `pixels`.
+    ///
+    /// # warning
+    /// * Panics if `pixels` is shorter than `byte_stride * pixel_height` or a row is shorter
+    ///   than `pixel_width` pixels.
+    pub fn append(&self, pixels: &[u8], pixel_width: i32, pixel_height: i32, byte_stride: i32) {
+        assert!(
+            byte_stride >= pixel_width * 4 && pixels.len() >= (byte_stride * pixel_height) as usize,
+            "Image pixels do not match the given size"
+        );
+        unsafe {
+            uiImageAppend(
+                self._inner,
+                pixels.as_ptr() as _,
+                pixel_width,
+                pixel_height,
+                byte_stride,
+            )
+        }
+    }
+
+    /// Creates a new image container.
+    /// Dimensions are measured in points. This is most commonly the pixel size of the `1x`
+    /// scaled image.
+    ///
+    /// # arguments
+    /// * `width`: Width in points.
+    /// * `height`: Height in points.
+    ///
+    /// # returns
+    /// * A new uiImage instance.
+    pub fn new(width: f64, height: f64) -> Self {
+        let ptr = unsafe { uiNewImage(width, height) };
+        Self { _inner: ptr }
+    }
+}
@@ -1,3 +1,11 @@
+#[cfg(target_os = "linux")]
+use crate::raw::{
+    uiDrawTextAlignCenter, uiDrawTextAlignLeft, uiDrawTextAlignRight, uiLabelEllipsizeEnd,
+    uiLabelEllipsizeMiddle, uiLabelEllipsizeMode, uiLabelEllipsizeNone, uiLabelEllipsizeStart,
+    uiLabelMaxWidth, uiLabelSelectable, uiLabelSetEllipsizeMode, uiLabelSetMarkup,
+    uiLabelSetMaxWidth, uiLabelSetSelectable, uiLabelSetTextAlign, uiLabelSetWrap,
+    uiLabelTextAlign, uiLabelWrap,
+};
 use {
     crate::{
         Control,
@@ -9,6 +17,29 @@ use {
     },
 };
 
+/// Horizontal alignment of text.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+/// Where to shorten text that does not fit.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum Ellipsize {
+    /// Never shorten the text.
+    #[default]
+    None,
+    /// Omit characters at the start, e.g. `…/src/label.rs`.
+    Start,
+    /// Omit characters in the middle, e.g. `/home/…/label.rs`.
+    Middle,
+    /// Omit characters at the end, e.g. `/home/user/…`.
+    End,
+}
+
 pub struct Label {
     _inner: *mut uiLabel,
 }
@@ -20,6 +51,8 @@ impl AsRef<Self> for Label {
 }
 
 impl Control for Label {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x4C61626C);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
@@ -62,4 +95,161 @@ impl Label {
         let ptr = unsafe { uiNewLabel(text.as_ptr()) };
         Ok(Self { _inner: ptr }.into())
     }
+
+    /// Sets the label text from Pango-like markup, e.g. `<b>bold</b>` or `<i>italic</i>`.
+    ///
+    /// # arguments
+    /// * `markup`: Label markup. `&`, `<` and `>` in plain text must be escaped.
+    ///
+    /// # returns
+    /// * `true` on success, `false` if the markup is invalid and the label was left unchanged.
+    ///
+    /// # note
+    /// * `text()` returns the text without markup.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_markup(&self, markup: &str) -> Result<bool, NulError> {
+        let markup = CString::new(markup)?;
+        Ok(unsafe { uiLabelSetMarkup(self._inner, markup.as_ptr()) } != 0)
+    }
+
+    /// Returns the horizontal alignment of the label text.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn align(&self) -> TextAlign {
+        let align = unsafe { uiLabelTextAlign(self._inner) };
+        if align == uiDrawTextAlignCenter as _ {
+            TextAlign::Center
+        } else if align == uiDrawTextAlignRight as _ {
+            TextAlign::Right
+        } else {
+            TextAlign::Left
+        }
+    }
+
+    /// Sets the horizontal alignment of the label text.
+    /// Applies to each line of wrapped text as well.
+    ///
+    /// # arguments
+    /// * `align`: The alignment.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_align(&self, align: TextAlign) {
+        let align = match align {
+            TextAlign::Left => uiDrawTextAlignLeft,
+            TextAlign::Center => uiDrawTextAlignCenter,
+            TextAlign::Right => uiDrawTextAlignRight,
+        };
+        unsafe { uiLabelSetTextAlign(self._inner, align as _) }
+    }
+
+    /// Returns whether the label text is wrapped.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn wrap(&self) -> bool {
+        unsafe { uiLabelWrap(self._inner) != 0 }
+    }
+
+    /// Sets whether the label text is wrapped at word boundaries.
+    /// Words longer than a line are broken between characters.
+    ///
+    /// # arguments
+    /// * `wrap`: `true` to wrap, `false` to keep the text on one line per line break.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_wrap(&self, wrap: bool) {
+        unsafe { uiLabelSetWrap(self._inner, wrap as _) }
+    }
+
+    /// Returns the desired maximum width of the label.
+    ///
+    /// # returns
+    /// * Maximum width in characters, `-1` if unset.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn max_width(&self) -> i32 {
+        unsafe { uiLabelMaxWidth(self._inner) }
+    }
+
+    /// Sets the desired maximum width of the label.
+    ///
+    /// # arguments
+    /// * `chars`: Maximum width in characters, `-1` to unset.
+    ///
+    /// # note
+    /// * Only has an effect if the label is wrapped or ellipsized.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_max_width(&self, chars: i32) {
+        unsafe { uiLabelSetMaxWidth(self._inner, chars) }
+    }
+
+    /// Returns where the label text is shortened if it does not fit.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn ellipsize(&self) -> Ellipsize {
+        let mode = unsafe { uiLabelEllipsizeMode(self._inner) };
+        if mode == uiLabelEllipsizeStart {
+            Ellipsize::Start
+        } else if mode == uiLabelEllipsizeMiddle {
+            Ellipsize::Middle
+        } else if mode == uiLabelEllipsizeEnd {
+            Ellipsize::End
+        } else {
+            Ellipsize::None
+        }
+    }
+
+    /// Sets where the label text is shortened if it does not fit.
+    /// An ellipsized label no longer requests the width of its whole text,
+    /// so long texts like file paths cannot widen the layout.
+    ///
+    /// # arguments
+    /// * `mode`: The ellipsize mode.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_ellipsize(&self, mode: Ellipsize) {
+        let mode = match mode {
+            Ellipsize::None => uiLabelEllipsizeNone,
+            Ellipsize::Start => uiLabelEllipsizeStart,
+            Ellipsize::Middle => uiLabelEllipsizeMiddle,
+            Ellipsize::End => uiLabelEllipsizeEnd,
+        };
+        unsafe { uiLabelSetEllipsizeMode(self._inner, mode as _) }
+    }
+
+    /// Returns whether the user can select the label text.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn selectable(&self) -> bool {
+        unsafe { uiLabelSelectable(self._inner) != 0 }
+    }
+
+    /// Sets whether the user can select and copy the label text.
+    ///
+    /// # arguments
+    /// * `selectable`: `true` to make the text selectable, `false` otherwise.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_selectable(&self, selectable: bool) {
+        unsafe { uiLabelSetSelectable(self._inner, selectable as _) }
+    }
 }
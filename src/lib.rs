@@ -1,16 +1,35 @@
+mod accelerator;
+#[cfg(target_os = "linux")]
+mod action;
 mod r#box;
 mod button;
 mod check_box;
+#[cfg(target_os = "linux")]
+pub mod clipboard;
+mod color;
 mod combo_box;
 mod control;
 mod date_time_picker;
+#[cfg(target_os = "linux")]
+mod drag_drop;
 mod editable_combo_box;
 mod entry;
 mod error;
+mod file_dialog;
+#[cfg(target_os = "linux")]
+mod focus;
+mod font;
 mod group;
+#[cfg(target_os = "linux")]
+mod handle;
+mod image;
 mod label;
+#[cfg(target_os = "linux")]
+mod log_view;
 mod macros;
 mod menu;
+#[cfg(target_os = "linux")]
+mod message_dialog;
 mod multi_line_entry;
 mod progress_bar;
 mod radio_buttons;
@@ -18,19 +37,30 @@ pub mod raw;
 mod separator;
 mod slider;
 mod spin_box;
+#[cfg(target_os = "linux")]
+mod style;
 mod tab;
+#[cfg(target_os = "linux")]
+mod validator;
 mod window;
+mod window_state;
 
 pub use {
-    r#box::*, button::*, check_box::*, combo_box::*, control::*, date_time_picker::*,
-    editable_combo_box::*, entry::*, group::*, label::*, menu::*, multi_line_entry::*,
-    progress_bar::*, radio_buttons::*, separator::*, slider::*, spin_box::*, tab::*, window::*,
+    accelerator::*, r#box::*, button::*, check_box::*, color::*, combo_box::*, control::*,
+    date_time_picker::*, editable_combo_box::*, entry::*, file_dialog::*, font::*, group::*,
+    image::*, label::*, menu::*, multi_line_entry::*, progress_bar::*, radio_buttons::*,
+    separator::*, slider::*, spin_box::*, tab::*, window::*, window_state::*,
 };
+#[cfg(target_os = "linux")]
+pub use {action::*, drag_drop::DropData, log_view::*, message_dialog::*, style::*, validator::*};
 
 use {
     error::UiError,
-    raw::{uiFreeInitError, uiInit, uiInitOptions, uiMain, uiQuit, uiUninit},
-    std::{ffi::CStr, mem::size_of},
+    raw::{uiFreeInitError, uiInit, uiInitOptions, uiMain, uiQueueMain, uiQuit, uiUninit},
+    std::{
+        ffi::{CStr, c_void},
+        mem::size_of,
+    },
 };
 
 pub fn init() -> Result<(), UiError> {
@@ -52,6 +82,7 @@ pub fn init() -> Result<(), UiError> {
 
 pub fn uninit() {
     unsafe { uiUninit() }
+    menu::clear_special_items();
 }
 
 pub fn main_loop() {
@@ -61,3 +92,27 @@ pub fn main_loop() {
 pub fn quit_loop() {
     unsafe { uiQuit() }
 }
+
+// `Box` is the layout control here.
+type Queued = std::boxed::Box<dyn FnOnce() + Send>;
+
+unsafe extern "C" fn queued(data: *mut c_void) {
+    let f = unsafe { std::boxed::Box::from_raw(data as *mut Queued) };
+    f()
+}
+
+/// Queues a function to be run on the main thread by the main loop.
+///
+/// # arguments
+/// * `f`: The function to run.
+///
+/// # note
+/// * This is the only function that can be called from other threads.
+pub fn queue_main<F>(f: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    let f: Queued = std::boxed::Box::new(f);
+    let data = std::boxed::Box::into_raw(std::boxed::Box::new(f));
+    unsafe { uiQueueMain(Some(queued), data as _) }
+}
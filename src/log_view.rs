@@ -0,0 +1,226 @@
+use {
+    crate::{Color, MultiLineEntry, error::UiError, handle::ControlHandle},
+    log::{Log, Metadata, Record},
+    std::{
+        ops::Deref,
+        sync::{Arc, Mutex, MutexGuard},
+    },
+};
+
+pub use log::{Level, LevelFilter};
+
+struct State {
+    level: LevelFilter,
+    max_lines: usize,
+    colors: [Option<Color>; 5],
+    installed: bool,
+    pending: Vec<(Level, String)>,
+}
+
+fn color_index(level: Level) -> usize {
+    level as usize - 1
+}
+
+/// Shows the pending records on the main thread.
+fn flush(state: &Mutex<State>, entry: MultiLineEntry) {
+    // Nothing in here may log, the logger would wait for the state forever.
+    let Ok(mut state) = state.lock() else {
+        return;
+    };
+    let State {
+        pending, colors, ..
+    } = &mut *state;
+    if pending.is_empty() {
+        return;
+    }
+    // Text added through `append()` may not end with a line break.
+    let last = entry.line_count() - 1;
+    if entry.line(last).is_ok_and(|l| l.is_some_and(|l| !l.is_empty())) {
+        let _ = entry.append("\n");
+    }
+    for (level, line) in pending.drain(..) {
+        let line = line.replace('\0', "");
+        let _ = match colors[color_index(level)] {
+            Some(color) => entry.append_with_color(&line, color),
+            None => entry.append(&line),
+        };
+    }
+
+    // The text always ends with a line break, followed by an empty last line.
+    let lines = entry.line_count() as usize - 1;
+    if state.max_lines > 0 && lines > state.max_lines {
+        entry.delete_lines(0, (lines - state.max_lines) as _);
+    }
+}
+
+/// A read only, auto-scrolling text pane showing log records.
+///
+/// Records are colored by level, filtered by a minimum level and the oldest lines are
+/// removed once `max_lines()` is exceeded.
+/// Use `install()` to make it the global logger of the `log` crate.
+///
+/// `log::Log` requires loggers to be shared between threads, which the control cannot be.
+/// The log view is therefore logged to through its [`LogViewHandle`], returned by
+/// `handle()`, which implements `log::Log` and can be used from any thread.
+/// Only available on Linux for now.
+pub struct LogView {
+    entry: MultiLineEntry,
+    handle: ControlHandle,
+    state: Arc<Mutex<State>>,
+}
+
+impl AsRef<MultiLineEntry> for LogView {
+    fn as_ref(&self) -> &MultiLineEntry {
+        &self.entry
+    }
+}
+
+impl Deref for LogView {
+    type Target = MultiLineEntry;
+
+    fn deref(&self) -> &Self::Target {
+        &self.entry
+    }
+}
+
+impl LogView {
+    fn lock(&self) -> Result<MutexGuard<'_, State>, UiError> {
+        Ok(self.state.lock()?)
+    }
+
+    /// Creates a new log view.
+    ///
+    /// # returns
+    /// * A log view showing records of every level, keeping at most 1000 lines.
+    pub fn new() -> Self {
+        let entry = MultiLineEntry::new_non_wrapping();
+        entry.set_read_only(true);
+        entry.set_auto_scroll(true);
+
+        let state = Arc::new(Mutex::new(State {
+            level: LevelFilter::Trace,
+            max_lines: 1000,
+            colors: [
+                Some(Color::rgb(0xcc0000)),
+                Some(Color::rgb(0xc47f00)),
+                None,
+                Some(Color::rgb(0x707070)),
+                Some(Color::rgb(0xa0a0a0)),
+            ],
+            installed: false,
+            pending: Vec::new(),
+        }));
+        let handle = ControlHandle::new(&entry);
+
+        Self {
+            entry,
+            handle,
+            state,
+        }
+    }
+
+    /// Returns the minimum level of the shown records.
+    pub fn level(&self) -> Result<LevelFilter, UiError> {
+        Ok(self.lock()?.level)
+    }
+
+    /// Sets the minimum level of the shown records.
+    ///
+    /// # arguments
+    /// * `level`: Records below this level are ignored, `LevelFilter::Off` to ignore all.
+    pub fn set_level(&self, level: LevelFilter) -> Result<(), UiError> {
+        let mut state = self.lock()?;
+        state.level = level;
+        if state.installed {
+            log::set_max_level(level);
+        }
+        Ok(())
+    }
+
+    /// Returns the maximum number of retained lines.
+    pub fn max_lines(&self) -> Result<usize, UiError> {
+        Ok(self.lock()?.max_lines)
+    }
+
+    /// Sets the maximum number of retained lines.
+    ///
+    /// # arguments
+    /// * `max_lines`: The oldest lines are removed beyond this number, `0` for unlimited.
+    pub fn set_max_lines(&self, max_lines: usize) -> Result<(), UiError> {
+        self.lock()?.max_lines = max_lines;
+        Ok(())
+    }
+
+    /// Sets the text color of records of a level.
+    ///
+    /// # arguments
+    /// * `level`: The level to color.
+    /// * `color`: Text color, `None` for the default text color.
+    pub fn set_level_color(&self, level: Level, color: Option<Color>) -> Result<(), UiError> {
+        self.lock()?.colors[color_index(level)] = color;
+        Ok(())
+    }
+
+    /// Returns the handle of the log view, a logger usable from any thread.
+    ///
+    /// # note
+    /// * Records logged after the log view was destroyed are dropped.
+    pub fn handle(&self) -> LogViewHandle {
+        LogViewHandle {
+            handle: self.handle.clone(),
+            state: self.state.clone(),
+        }
+    }
+
+    /// Makes the log view the global logger of the `log` crate.
+    ///
+    /// # note
+    /// * The global logger can only be set once per program.
+    pub fn install(&self) -> Result<(), UiError> {
+        let level = self.level()?;
+        log::set_logger(Box::leak(Box::new(self.handle())))?;
+        log::set_max_level(level);
+        self.lock()?.installed = true;
+        Ok(())
+    }
+}
+
+/// The handle of a [`LogView`], created by `LogView::handle()`.
+///
+/// It implements `log::Log` for the log view and can be sent to other threads.
+/// Records are shown once the main loop runs, also when logged on the main thread.
+/// Only available on Linux for now.
+#[derive(Clone)]
+pub struct LogViewHandle {
+    handle: ControlHandle,
+    state: Arc<Mutex<State>>,
+}
+
+impl Log for LogViewHandle {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.handle.is_alive() && self.state.lock().is_ok_and(|s| metadata.level() <= s.level)
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let line = format!(
+            "[{:<5}] {}: {}\n",
+            record.level(),
+            record.target(),
+            record.args()
+        );
+
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        state.pending.push((record.level(), line));
+        drop(state);
+        // Records logged until the main loop gets to them are shown in one go.
+        let state = self.state.clone();
+        self.handle.queue(move |entry| flush(&state, entry));
+    }
+
+    fn flush(&self) {}
+}
@@ -21,19 +21,26 @@ macro_rules! define_callback_function {
             'b: 'a,
         {
             static STATE: Mutex<
-                Option<HashMap<isize, Box<dyn FnMut(*mut $raw_self_type $(, *mut $arg_raw_type)*, *mut c_void) -> $raw_ret_type + Send>>>,
+                Option<HashMap<isize, ::std::sync::Arc<Mutex<dyn FnMut(*mut $raw_self_type $(, *mut $arg_raw_type)*, *mut c_void) -> $raw_ret_type + Send>>>>,
             > = Mutex::new(None);
 
             unsafe extern "C" fn cb_(w: *mut $raw_self_type $(, $arg_name: *mut $arg_raw_type)*, data: *mut c_void) -> $raw_ret_type {
-                match modify_callback!(STATE, c, {
-                    c.get_mut(&(w as *const _ as _))
-                        .and_then(|f| Some(f(w $(, $arg_name)*, data)))
-                }) {
+                // The map is not kept locked, so the callback can register callbacks or run a nested loop.
+                let f = match modify_callback!(STATE, c, { c.get(&(w as *const _ as _)).cloned() }) {
+                    Ok(Some(f)) => f,
+                    Ok(None) => return Default::default(),
+                    Err(e) => {
+                        error!("An error was occurred in {}: {}", stringify!($inner), e);
+                        return Default::default();
+                    }
+                };
+                // A callback triggered again while it is still running is skipped.
+                match f.try_lock() {
+                    Ok(mut f) => f(w $(, $arg_name)*, data),
                     Err(e) => {
                         error!("An error was occurred in {}: {}", stringify!($inner), e);
                         Default::default()
                     }
-                    Ok(r) => r.unwrap_or_default(),
                 }
             }
 
@@ -42,10 +49,10 @@ macro_rules! define_callback_function {
                     modify_callback!(STATE, c, {
                         c.insert(
                             self._inner as _,
-                            Box::new(move |w $(, $arg_name)*, d| {
+                            ::std::sync::Arc::new(Mutex::new(move |w $(, $arg_name)*, d| {
                                 let self_ = Self::from_ptr(w as _);
                                 unsafe { f(self_ $(, <$arg_wrapped_type>::from_ptr($arg_name as _))*, transmute(d as *mut T)) }
-                            }),
+                            })),
                         )
                     })?;
                     Some(cb_ as _)
@@ -60,3 +67,67 @@ macro_rules! define_callback_function {
         }
     };
 }
+
+/// Describes a whole menu bar as nested data and returns a [`MenuBar`](crate::MenuBar).
+///
+/// Supported entries are `item(id, text [, accelerator])`, `check(id, text, checked [, accelerator])`,
+/// `separator`, `submenu(text) => [...]`, `preferences(id)`, `about(id)` and `quit`.
+/// Only available on Linux for now.
+///
+/// ```no_run
+/// use system_ui::*;
+///
+/// #[derive(Clone, Eq, Hash, PartialEq)]
+/// enum Cmd {
+///     Open,
+///     Wrap,
+///     About,
+/// }
+///
+/// let items = menu! {
+///     "File" => [
+///         item(Cmd::Open, "Open", "Ctrl+O"),
+///         separator,
+///         quit,
+///     ],
+///     "View" => [check(Cmd::Wrap, "Word wrap", true)],
+///     "Help" => [about(Cmd::About)],
+/// }
+/// .build(|id, _item, window| {
+///     if let Cmd::About = id {
+///         window.msg_box("About", "Menu demo").ok();
+///     }
+/// })?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[cfg(target_os = "linux")]
+#[macro_export]
+macro_rules! menu {
+    (@entries [$($done:expr),*]) => {
+        vec![$($done),*]
+    };
+    (@entries [$($done:expr),*] item($id:expr, $text:expr $(, $acc:expr)?) $(, $($rest:tt)*)?) => {
+        $crate::menu!(@entries [$($done,)* $crate::MenuEntry::item($id, $text)$(.accelerator($acc))?] $($($rest)*)?)
+    };
+    (@entries [$($done:expr),*] check($id:expr, $text:expr, $checked:expr $(, $acc:expr)?) $(, $($rest:tt)*)?) => {
+        $crate::menu!(@entries [$($done,)* $crate::MenuEntry::check($id, $text, $checked)$(.accelerator($acc))?] $($($rest)*)?)
+    };
+    (@entries [$($done:expr),*] separator $(, $($rest:tt)*)?) => {
+        $crate::menu!(@entries [$($done,)* $crate::MenuEntry::separator()] $($($rest)*)?)
+    };
+    (@entries [$($done:expr),*] submenu($text:expr) => [$($sub:tt)*] $(, $($rest:tt)*)?) => {
+        $crate::menu!(@entries [$($done,)* $crate::MenuEntry::submenu($text, $crate::menu!(@entries [] $($sub)*))] $($($rest)*)?)
+    };
+    (@entries [$($done:expr),*] preferences($id:expr) $(, $($rest:tt)*)?) => {
+        $crate::menu!(@entries [$($done,)* $crate::MenuEntry::preferences($id)] $($($rest)*)?)
+    };
+    (@entries [$($done:expr),*] about($id:expr) $(, $($rest:tt)*)?) => {
+        $crate::menu!(@entries [$($done,)* $crate::MenuEntry::about($id)] $($($rest)*)?)
+    };
+    (@entries [$($done:expr),*] quit $(, $($rest:tt)*)?) => {
+        $crate::menu!(@entries [$($done,)* $crate::MenuEntry::quit()] $($($rest)*)?)
+    };
+    ($($name:expr => [$($entries:tt)*]),* $(,)?) => {
+        $crate::MenuBar::new()$(.menu($name, $crate::menu!(@entries [] $($entries)*)))*
+    };
+}
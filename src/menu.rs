@@ -1,17 +1,61 @@
+#[cfg(target_os = "linux")]
+mod bar;
 mod item;
+#[cfg(target_os = "linux")]
+mod popup;
 
+#[cfg(target_os = "linux")]
+use crate::raw::{uiMenuAppendSubmenu, uiMenuDelete};
+#[cfg(target_os = "linux")]
+pub use bar::{MenuBar, MenuEntry, MenuItems};
 pub use item::MenuItem;
+#[cfg(target_os = "linux")]
+pub use popup::{PopupMenu, PopupSubmenu};
 use {
     crate::{
         Control,
         raw::{
             uiControl, uiMenu, uiMenuAppendAboutItem, uiMenuAppendCheckItem, uiMenuAppendItem,
-            uiMenuAppendPreferencesItem, uiMenuAppendQuitItem, uiMenuAppendSeparator, uiNewMenu,
+            uiMenuAppendPreferencesItem, uiMenuAppendQuitItem, uiMenuAppendSeparator, uiMenuItem,
+            uiNewMenu,
         },
     },
-    std::ffi::{CString, NulError},
+    std::{
+        ffi::{CString, NulError},
+        sync::Mutex,
+    },
 };
 
+/// The `Quit`, `Preferences` and `About` items created so far; each may only exist once.
+static SPECIAL_ITEMS: Mutex<Vec<(&'static str, isize)>> = Mutex::new(Vec::new());
+
+fn add_special_item(name: &'static str, ptr: *mut uiMenuItem) {
+    if let Ok(mut items) = SPECIAL_ITEMS.lock() {
+        items.push((name, ptr as _));
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn remove_special_item(ptr: *mut uiMenuItem) {
+    if let Ok(mut items) = SPECIAL_ITEMS.lock() {
+        items.retain(|(_, p)| *p != ptr as isize);
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn special_item_created(name: &str) -> bool {
+    SPECIAL_ITEMS
+        .lock()
+        .map(|items| items.iter().any(|(n, _)| *n == name))
+        .unwrap_or_default()
+}
+
+pub(crate) fn clear_special_items() {
+    if let Ok(mut items) = SPECIAL_ITEMS.lock() {
+        items.clear();
+    }
+}
+
 pub struct Menu {
     _inner: *mut uiMenu,
 }
@@ -68,6 +112,7 @@ impl Menu {
     /// * Only one such menu item may exist per application.
     pub fn append_quit_item(&self) -> MenuItem {
         let ptr = unsafe { uiMenuAppendQuitItem(self._inner) };
+        add_special_item("Quit", ptr);
         MenuItem::from_ptr(ptr as _).into()
     }
 
@@ -80,6 +125,7 @@ impl Menu {
     /// * Only one such menu item may exist per application.
     pub fn append_preferences_item(&self) -> MenuItem {
         let ptr = unsafe { uiMenuAppendPreferencesItem(self._inner) };
+        add_special_item("Preferences", ptr);
         MenuItem::from_ptr(ptr as _).into()
     }
 
@@ -92,6 +138,7 @@ impl Menu {
     /// * A new uiMenuItem instance.
     pub fn append_about_item(&self) -> MenuItem {
         let ptr = unsafe { uiMenuAppendAboutItem(self._inner) };
+        add_special_item("About", ptr);
         MenuItem::from_ptr(ptr as _).into()
     }
 
@@ -100,6 +147,37 @@ impl Menu {
         unsafe { uiMenuAppendSeparator(self._inner) }
     }
 
+    /// Appends a new submenu.
+    ///
+    /// # arguments
+    /// * `name`: Submenu label.
+    ///
+    /// # returns
+    /// * A new uiMenu instance.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn append_submenu(&self, name: &str) -> Result<Self, NulError> {
+        let name = CString::new(name)?;
+        let ptr = unsafe { uiMenuAppendSubmenu(self._inner, name.as_ptr()) };
+        Ok(Self { _inner: ptr })
+    }
+
+    /// Removes the submenu from its parent menu and frees it.
+    ///
+    /// # note
+    /// * Menus already shown in a window are updated as well.
+    /// * Only available on Linux for now.
+    ///
+    /// # warning
+    /// * Only menus created via `append_submenu()` can be deleted.
+    /// * The menu and its items must not be used afterwards.
+    #[cfg(target_os = "linux")]
+    pub fn delete(&self) {
+        unsafe { uiMenuDelete(self._inner) }
+    }
+
     /// Creates a new menu.
     /// Typical values are `File`, `Edit`, `Help`.
     ///
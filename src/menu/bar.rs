@@ -0,0 +1,354 @@
+use {
+    crate::{
+        Accelerator, Menu, MenuItem, Window, error::UiError, menu::special_item_created,
+        window::window_created,
+    },
+    log::error,
+    std::{
+        collections::{HashMap, HashSet},
+        hash::Hash,
+        sync::{Arc, Mutex},
+    },
+};
+
+enum Kind<I> {
+    Item {
+        id: I,
+        text: String,
+        checkable: bool,
+        checked: bool,
+        accelerator: Option<String>,
+    },
+    Separator,
+    Submenu {
+        text: String,
+        entries: Vec<MenuEntry<I>>,
+    },
+    Quit,
+    Preferences(I),
+    About(I),
+}
+
+/// An entry of a declaratively described menu.
+/// Usually created through the [`menu!`](crate::menu!) macro.
+pub struct MenuEntry<I> {
+    kind: Kind<I>,
+}
+
+impl<I> MenuEntry<I> {
+    /// Describes a generic menu item.
+    ///
+    /// # arguments
+    /// * `id`: Identifier passed to the dispatch callback.
+    /// * `text`: Menu item text.
+    pub fn item(id: I, text: &str) -> Self {
+        Self {
+            kind: Kind::Item {
+                id,
+                text: text.into(),
+                checkable: false,
+                checked: false,
+                accelerator: None,
+            },
+        }
+    }
+
+    /// Describes a menu item with a checkbox.
+    ///
+    /// # arguments
+    /// * `id`: Identifier passed to the dispatch callback.
+    /// * `text`: Menu item text.
+    /// * `checked`: Initial check state.
+    pub fn check(id: I, text: &str, checked: bool) -> Self {
+        Self {
+            kind: Kind::Item {
+                id,
+                text: text.into(),
+                checkable: true,
+                checked,
+                accelerator: None,
+            },
+        }
+    }
+
+    /// Describes a separator.
+    pub fn separator() -> Self {
+        Self {
+            kind: Kind::Separator,
+        }
+    }
+
+    /// Describes a submenu.
+    ///
+    /// # arguments
+    /// * `text`: Submenu label.
+    /// * `entries`: Entries of the submenu.
+    pub fn submenu(text: &str, entries: Vec<Self>) -> Self {
+        Self {
+            kind: Kind::Submenu {
+                text: text.into(),
+                entries,
+            },
+        }
+    }
+
+    /// Describes the `Quit` menu item.
+    ///
+    /// # note
+    /// * The dispatch callback is not called for this item, use `on_should_quit` instead.
+    pub fn quit() -> Self {
+        Self { kind: Kind::Quit }
+    }
+
+    /// Describes the `Preferences` menu item.
+    ///
+    /// # arguments
+    /// * `id`: Identifier passed to the dispatch callback.
+    pub fn preferences(id: I) -> Self {
+        Self {
+            kind: Kind::Preferences(id),
+        }
+    }
+
+    /// Describes the `About` menu item.
+    ///
+    /// # arguments
+    /// * `id`: Identifier passed to the dispatch callback.
+    pub fn about(id: I) -> Self {
+        Self {
+            kind: Kind::About(id),
+        }
+    }
+
+    /// Sets the keyboard shortcut of a generic or check item.
+    ///
+    /// # arguments
+    /// * `accelerator`: The shortcut, e.g. `"Ctrl+S"`. It is validated by [`MenuBar::build`].
+    pub fn accelerator(mut self, accelerator: &str) -> Self {
+        if let Kind::Item {
+            accelerator: ref mut a,
+            ..
+        } = self.kind
+        {
+            *a = Some(accelerator.into());
+        }
+        self
+    }
+}
+
+/// A declarative description of the whole menu bar.
+///
+/// # note
+/// * All menus have to be built before the first window is created.
+/// * Only available on Linux for now.
+pub struct MenuBar<I> {
+    menus: Vec<(String, Vec<MenuEntry<I>>)>,
+}
+
+impl<I> Default for MenuBar<I> {
+    fn default() -> Self {
+        Self { menus: Vec::new() }
+    }
+}
+
+impl<I> MenuBar<I>
+where
+    I: Clone + Eq + Hash + Send + 'static,
+{
+    /// Creates an empty menu bar description.
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Appends a top level menu.
+    ///
+    /// # arguments
+    /// * `name`: Menu label.
+    /// * `entries`: Entries of the menu.
+    pub fn menu(mut self, name: &str, entries: Vec<MenuEntry<I>>) -> Self {
+        self.menus.push((name.into(), entries));
+        self
+    }
+
+    /// Creates all described menus.
+    ///
+    /// # arguments
+    /// * `f`: Dispatch callback for all menu items.
+    ///          @p id Identifier of the clicked item.
+    ///          @p item The clicked menu item.
+    ///          @p window Reference to the window from which the callback got triggered.
+    ///
+    /// # returns
+    /// * The created menu items by id.
+    ///
+    /// # note
+    /// * Fails without creating anything if a window was already created, an id is used twice,
+    ///   an accelerator cannot be parsed or more than one `Quit`, `Preferences` or `About` item
+    ///   is described or one of them was already created elsewhere.
+    pub fn build<F>(self, f: F) -> Result<MenuItems<I>, UiError>
+    where
+        F: FnMut(&I, MenuItem, Window) + Send + 'static,
+    {
+        if window_created() {
+            return Err(UiError::Menu(
+                "Menus must be created before the first window".into(),
+            ));
+        }
+
+        let mut ids = HashSet::new();
+        let mut specials = HashSet::new();
+        for (_, entries) in &self.menus {
+            validate(entries, &mut ids, &mut specials)?;
+        }
+
+        let f = Arc::new(Mutex::new(f));
+        let mut items = HashMap::new();
+        for (name, entries) in self.menus {
+            let menu = Menu::new(&name)?;
+            append(&menu, entries, &f, &mut items)?;
+        }
+        Ok(MenuItems { items })
+    }
+}
+
+fn validate<'a, I>(
+    entries: &'a [MenuEntry<I>],
+    ids: &mut HashSet<&'a I>,
+    specials: &mut HashSet<&'static str>,
+) -> Result<(), UiError>
+where
+    I: Eq + Hash,
+{
+    for entry in entries {
+        let id = match &entry.kind {
+            Kind::Item {
+                id, accelerator, ..
+            } => {
+                if let Some(a) = accelerator {
+                    a.parse::<Accelerator>()?;
+                }
+                id
+            }
+            Kind::Separator => continue,
+            Kind::Submenu { entries, .. } => {
+                validate(entries, ids, specials)?;
+                continue;
+            }
+            Kind::Quit => {
+                check_special(specials, "Quit")?;
+                continue;
+            }
+            Kind::Preferences(id) => {
+                check_special(specials, "Preferences")?;
+                id
+            }
+            Kind::About(id) => {
+                check_special(specials, "About")?;
+                id
+            }
+        };
+        if !ids.insert(id) {
+            return Err(UiError::Menu("Duplicate menu item id".into()));
+        }
+    }
+    Ok(())
+}
+
+fn check_special(specials: &mut HashSet<&'static str>, name: &'static str) -> Result<(), UiError> {
+    match !special_item_created(name) && specials.insert(name) {
+        true => Ok(()),
+        false => Err(UiError::Menu(format!("Only one {} item may exist", name))),
+    }
+}
+
+fn append<I, F>(
+    menu: &Menu,
+    entries: Vec<MenuEntry<I>>,
+    f: &Arc<Mutex<F>>,
+    items: &mut HashMap<I, MenuItem>,
+) -> Result<(), UiError>
+where
+    I: Clone + Eq + Hash + Send + 'static,
+    F: FnMut(&I, MenuItem, Window) + Send + 'static,
+{
+    for entry in entries {
+        let (id, item) = match entry.kind {
+            Kind::Item {
+                id,
+                text,
+                checkable,
+                checked,
+                accelerator,
+            } => {
+                let item = match checkable {
+                    true => menu.append_check_item(&text)?,
+                    false => menu.append_item(&text)?,
+                };
+                if checked {
+                    item.set_checked(true);
+                }
+                if let Some(a) = accelerator {
+                    item.set_accelerator(Some(a.parse()?));
+                }
+                (id, item)
+            }
+            Kind::Separator => {
+                menu.append_separator();
+                continue;
+            }
+            Kind::Submenu { text, entries } => {
+                append(&menu.append_submenu(&text)?, entries, f, items)?;
+                continue;
+            }
+            Kind::Quit => {
+                menu.append_quit_item();
+                continue;
+            }
+            Kind::Preferences(id) => (id, menu.append_preferences_item()),
+            Kind::About(id) => (id, menu.append_about_item()),
+        };
+
+        let f = f.clone();
+        let id_ = id.clone();
+        item.on_clicked(
+            move |item, window, _| match f.lock() {
+                Ok(mut f) => f(&id_, item, window),
+                Err(e) => error!("An error was occurred in menu dispatch: {}", e),
+            },
+            &mut (),
+        )?;
+        items.insert(id, item);
+    }
+    Ok(())
+}
+
+/// The menu items created by [`MenuBar::build`], looked up by id.
+pub struct MenuItems<I> {
+    items: HashMap<I, MenuItem>,
+}
+
+impl<I> MenuItems<I>
+where
+    I: Eq + Hash,
+{
+    /// Returns the menu item with the given id.
+    pub fn get(&self, id: &I) -> Option<&MenuItem> {
+        self.items.get(id)
+    }
+
+    /// Returns whether the check item with the given id is checked.
+    ///
+    /// # returns
+    /// * `None` if there is no item with this id.
+    pub fn checked(&self, id: &I) -> Option<bool> {
+        self.items.get(id).map(MenuItem::checked)
+    }
+
+    /// Sets whether the check item with the given id is checked.
+    ///
+    /// # returns
+    /// * `false` if there is no item with this id.
+    pub fn set_checked(&self, id: &I, checked: bool) -> bool {
+        self.items.get(id).map(|i| i.set_checked(checked)).is_some()
+    }
+}
@@ -1,3 +1,19 @@
+#[cfg(target_os = "linux")]
+use {
+    crate::{
+        Accelerator,
+        action::forget_bound,
+        menu::remove_special_item,
+        raw::{
+            uiFreeText, uiMenuItemDelete, uiMenuItemSetAccelerator, uiMenuItemSetText,
+            uiMenuItemText,
+        },
+    },
+    std::{
+        ffi::{CStr, CString, NulError},
+        str::Utf8Error,
+    },
+};
 use {
     crate::{
         Control, Window, define_callback_function,
@@ -96,4 +112,69 @@ impl MenuItem {
     pub fn set_checked(&self, checked: bool) {
         unsafe { uiMenuItemSetChecked(self._inner, checked as _) }
     }
+
+    /// Returns the menu item text.
+    ///
+    /// # returns
+    /// * The text of the menu item.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn text(&self) -> Result<String, Utf8Error> {
+        let ptr = unsafe { uiMenuItemText(self._inner) };
+        let text = unsafe { CStr::from_ptr(ptr) }.to_str()?.into();
+        unsafe { uiFreeText(ptr) };
+        Ok(text)
+    }
+
+    /// Sets the menu item text.
+    ///
+    /// # arguments
+    /// * `text`: Menu item text.
+    ///
+    /// # note
+    /// * Menu items already shown in a window are updated as well.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_text(&self, text: &str) -> Result<(), NulError> {
+        let text = CString::new(text)?;
+        Ok(unsafe { uiMenuItemSetText(self._inner, text.as_ptr()) })
+    }
+
+    /// Sets the keyboard shortcut activating the menu item.
+    ///
+    /// # arguments
+    /// * `accelerator`: The shortcut, e.g. `"Ctrl+S".parse()?`, `None` to remove it.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    ///
+    /// # warning
+    /// * Submenus cannot have accelerators.
+    #[cfg(target_os = "linux")]
+    pub fn set_accelerator(&self, accelerator: Option<Accelerator>) {
+        let (modifiers, (key, ext_key)) = match accelerator {
+            Some(a) => (a.modifiers.bits(), a.key.to_raw()),
+            None => (0, (0, 0)),
+        };
+        unsafe { uiMenuItemSetAccelerator(self._inner, modifiers as _, key, ext_key) }
+    }
+
+    /// Removes the menu item from its menu and frees it.
+    ///
+    /// # note
+    /// * Menu items already shown in a window are removed as well.
+    /// * Only available on Linux for now.
+    ///
+    /// # warning
+    /// * The menu item must not be used afterwards.
+    #[cfg(target_os = "linux")]
+    pub fn delete(&self) {
+        remove_special_item(self._inner);
+        if let Err(e) = forget_bound(self._inner as _) {
+            error!("An error was occurred in delete: {}", e);
+        }
+        unsafe { uiMenuItemDelete(self._inner) }
+    }
 }
@@ -0,0 +1,149 @@
+use {
+    crate::{
+        Control, Menu, MenuItem,
+        error::UiError,
+        raw::{uiMenu, uiMenuPopup, uiMenuPopupAt, uiNewPopupMenu},
+    },
+    std::ffi::NulError,
+};
+
+/// Methods shared by popup menus and their submenus. `Quit`, `Preferences` and `About`
+/// items are left out since they cannot be added to popup menus.
+macro_rules! popup_items {
+    () => {
+        /// Appends a generic menu item.
+        ///
+        /// # arguments
+        /// * `name`: Menu item text.
+        ///
+        /// # returns
+        /// * A new uiMenuItem instance.
+        pub fn append_item(&self, name: &str) -> Result<MenuItem, NulError> {
+            self.menu.append_item(name)
+        }
+
+        /// Appends a generic menu item with a checkbox.
+        ///
+        /// # arguments
+        /// * `name`: Menu item text.
+        ///
+        /// # returns
+        /// * A new uiMenuItem instance.
+        pub fn append_check_item(&self, name: &str) -> Result<MenuItem, NulError> {
+            self.menu.append_check_item(name)
+        }
+
+        /// Appends a new separator.
+        pub fn append_separator(&self) {
+            self.menu.append_separator()
+        }
+
+        /// Appends a new submenu.
+        ///
+        /// # arguments
+        /// * `name`: Submenu label.
+        ///
+        /// # returns
+        /// * A new submenu instance.
+        pub fn append_submenu(&self, name: &str) -> Result<PopupSubmenu, NulError> {
+            Ok(PopupSubmenu {
+                menu: self.menu.append_submenu(name)?,
+            })
+        }
+    };
+}
+
+/// A menu shown on demand, e.g. when a control is right-clicked.
+///
+/// Popup menus can be created at any time.
+/// Only available on Linux for now.
+pub struct PopupMenu {
+    menu: Menu,
+}
+
+impl PopupMenu {
+    popup_items!();
+
+    pub(crate) fn as_ptr_mut(&self) -> *mut uiMenu {
+        self.menu.as_ptr_mut() as _
+    }
+
+    fn shown(shown: i32) -> Result<(), UiError> {
+        match shown {
+            0 => Err(UiError::Menu(
+                "Popup menus can only be shown for controls inside a window".into(),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Shows the popup menu at the mouse pointer.
+    ///
+    /// # arguments
+    /// * `control`: The control the menu is shown for.
+    ///
+    /// # returns
+    /// * An error if `control` is not inside a window.
+    ///
+    /// # note
+    /// * The window passed to menu item callbacks is the window containing `control`.
+    pub fn popup<C, I>(&self, control: C) -> Result<(), UiError>
+    where
+        C: AsRef<I>,
+        I: Control,
+    {
+        Self::shown(unsafe { uiMenuPopup(self.as_ptr_mut(), control.as_ref().as_ptr_mut()) })
+    }
+
+    /// Shows the popup menu at a position relative to a control.
+    ///
+    /// # arguments
+    /// * `control`: The control the menu is shown for.
+    /// * `x`: X position relative to the top left corner of `control`.
+    /// * `y`: Y position relative to the top left corner of `control`.
+    ///
+    /// # returns
+    /// * An error if `control` is not inside a window.
+    ///
+    /// # note
+    /// * The window passed to menu item callbacks is the window containing `control`.
+    pub fn popup_at<C, I>(&self, control: C, x: i32, y: i32) -> Result<(), UiError>
+    where
+        C: AsRef<I>,
+        I: Control,
+    {
+        Self::shown(unsafe {
+            uiMenuPopupAt(self.as_ptr_mut(), control.as_ref().as_ptr_mut(), x, y)
+        })
+    }
+
+    /// Creates a new popup menu.
+    ///
+    /// # returns
+    /// * A new uiMenu instance.
+    pub fn new() -> Self {
+        let ptr = unsafe { uiNewPopupMenu() };
+        Self {
+            menu: Menu::from_ptr(ptr as _),
+        }
+    }
+}
+
+/// A submenu of a [`PopupMenu`].
+///
+/// Only available on Linux for now.
+pub struct PopupSubmenu {
+    menu: Menu,
+}
+
+impl PopupSubmenu {
+    popup_items!();
+
+    /// Removes the submenu from its parent menu and frees it.
+    ///
+    /// # warning
+    /// * The menu and its items must not be used afterwards.
+    pub fn delete(&self) {
+        self.menu.delete()
+    }
+}
@@ -0,0 +1,170 @@
+use {
+    crate::{
+        Control, Window,
+        error::UiError,
+        raw::{
+            uiMessageDialog, uiMessageDialogIcon, uiMessageDialogIconError,
+            uiMessageDialogIconInfo, uiMessageDialogIconQuestion, uiMessageDialogIconWarning,
+            uiMsgBoxConfirm,
+        },
+    },
+    std::ffi::{CString, NulError},
+};
+
+/// The kind of a message, shown as icon.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MessageKind {
+    #[default]
+    Info,
+    Warning,
+    Error,
+    Question,
+}
+
+impl MessageKind {
+    fn to_raw(self) -> uiMessageDialogIcon {
+        match self {
+            Self::Info => uiMessageDialogIconInfo,
+            Self::Warning => uiMessageDialogIconWarning,
+            Self::Error => uiMessageDialogIconError,
+            Self::Question => uiMessageDialogIconQuestion,
+        }
+    }
+}
+
+/// Builder for message dialog windows asking the user to choose a button.
+/// Only available on Linux for now.
+#[derive(Clone, Debug, Default)]
+pub struct MessageDialog {
+    kind: MessageKind,
+    title: String,
+    text: String,
+    buttons: Vec<String>,
+    default_button: Option<usize>,
+    cancel_button: Option<usize>,
+}
+
+impl MessageDialog {
+    /// Creates a new informational dialog with a single `OK` button.
+    ///
+    /// # arguments
+    /// * `title`: Dialog window title text.
+    /// * `text`: Dialog message text.
+    pub fn new(title: &str, text: &str) -> Self {
+        Self {
+            title: title.into(),
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the kind of the message. [Default: `MessageKind::Info`]
+    pub fn kind(mut self, kind: MessageKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Appends a button. Buttons are shown from left to right.
+    ///
+    /// # arguments
+    /// * `label`: Button label text.
+    pub fn button(mut self, label: &str) -> Self {
+        self.buttons.push(label.into());
+        self
+    }
+
+    /// Sets the button activated by `Enter`.
+    ///
+    /// # arguments
+    /// * `index`: Index of the button in the order of appending.
+    pub fn default_button(mut self, index: usize) -> Self {
+        self.default_button = Some(index);
+        self
+    }
+
+    /// Sets the button reported when the dialog is closed or `Escape` is pressed.
+    ///
+    /// # arguments
+    /// * `index`: Index of the button in the order of appending.
+    pub fn cancel_button(mut self, index: usize) -> Self {
+        self.cancel_button = Some(index);
+        self
+    }
+
+    /// Shows the dialog and waits for the user to choose a button.
+    ///
+    /// # arguments
+    /// * `parent`: Parent window.
+    ///
+    /// # returns
+    /// * Index of the chosen button, `None` if the dialog was closed without a cancel button.
+    ///
+    /// # note
+    /// * Fails without showing the dialog if the default or cancel button is set without
+    ///   appending buttons or refers to a button that was not appended.
+    pub fn show(&self, parent: &Window) -> Result<Option<usize>, UiError> {
+        self.validate()?;
+        let title = CString::new(self.title.as_str())?;
+        let text = CString::new(self.text.as_str())?;
+        let buttons = self
+            .buttons
+            .iter()
+            .map(|b| CString::new(b.as_str()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let button_ptrs = buttons.iter().map(|b| b.as_ptr()).collect::<Vec<_>>();
+        let index = |i: Option<usize>| i.map_or(-1, |i| i as _);
+
+        let chosen = unsafe {
+            uiMessageDialog(
+                parent.as_ptr_mut() as _,
+                self.kind.to_raw(),
+                title.as_ptr(),
+                text.as_ptr(),
+                button_ptrs.as_ptr() as _,
+                buttons.len() as _,
+                index(self.default_button),
+                index(self.cancel_button),
+            )
+        };
+        Ok(usize::try_from(chosen).ok())
+    }
+
+    fn validate(&self) -> Result<(), UiError> {
+        for (name, index) in [
+            ("default", self.default_button),
+            ("cancel", self.cancel_button),
+        ] {
+            match index {
+                Some(i) if i >= self.buttons.len() => {
+                    return Err(UiError::Dialog(format!(
+                        "The {} button {} does not exist, only {} buttons were appended",
+                        name,
+                        i,
+                        self.buttons.len()
+                    )));
+                }
+                _ => (),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Asks the user a yes/no question.
+///
+/// # arguments
+/// * `window`: Parent window.
+/// * `title`: Dialog window title text.
+/// * `text`: Dialog message text.
+///
+/// # returns
+/// * `true` if the user confirmed, `false` if declined or closed the dialog.
+///
+/// # note
+/// * The buttons are labeled and ordered following the platform conventions.
+/// * Only available on Linux for now.
+pub fn confirm(window: &Window, title: &str, text: &str) -> Result<bool, NulError> {
+    let title = CString::new(title)?;
+    let text = CString::new(text)?;
+    Ok(unsafe { uiMsgBoxConfirm(window.as_ptr_mut() as _, title.as_ptr(), text.as_ptr()) } != 0)
+}
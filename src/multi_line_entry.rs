@@ -1,3 +1,15 @@
+#[cfg(target_os = "linux")]
+use crate::{
+    Color,
+    raw::{
+        uiMultilineEntryAppendWithColor, uiMultilineEntryAutoScroll,
+        uiMultilineEntryCursorPosition, uiMultilineEntryDeleteLines,
+        uiMultilineEntryInsertAtCursor, uiMultilineEntryLine, uiMultilineEntryLineColor,
+        uiMultilineEntryLineCount, uiMultilineEntryScrollToEnd, uiMultilineEntrySelectRange,
+        uiMultilineEntrySelection, uiMultilineEntrySetAutoScroll,
+        uiMultilineEntrySetCursorPosition,
+    },
+};
 use {
     crate::{
         Control, define_callback_function,
@@ -31,6 +43,8 @@ impl AsRef<Self> for MultiLineEntry {
 }
 
 impl Control for MultiLineEntry {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x4D6C6E45);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
@@ -114,6 +128,199 @@ impl MultiLineEntry {
         unsafe { uiMultilineEntrySetReadOnly(self._inner, readonly as _) }
     }
 
+    /// Inserts text at the multi line entry's text cursor.
+    ///
+    /// # arguments
+    /// * `text`: Text to insert.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn insert_at_cursor(&self, text: &str) -> Result<(), NulError> {
+        let text = CString::new(text)?;
+        Ok(unsafe { uiMultilineEntryInsertAtCursor(self._inner, text.as_ptr()) })
+    }
+
+    /// Appends text in a color to the multi line entry's text.
+    ///
+    /// # arguments
+    /// * `text`: Text to append.
+    /// * `color`: Color of the appended text.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn append_with_color(&self, text: &str, color: Color) -> Result<(), NulError> {
+        let text = CString::new(text)?;
+        Ok(unsafe {
+            uiMultilineEntryAppendWithColor(
+                self._inner,
+                text.as_ptr(),
+                color.r,
+                color.g,
+                color.b,
+                color.a,
+            )
+        })
+    }
+
+    /// Deletes whole lines of the multi line entry's text.
+    ///
+    /// # arguments
+    /// * `start`: Index of the first line to delete, starting at `0`.
+    /// * `count`: Number of lines to delete, including their line breaks.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn delete_lines(&self, start: i32, count: i32) {
+        unsafe { uiMultilineEntryDeleteLines(self._inner, start, count) }
+    }
+
+    /// Returns the position of the multi line entry's text cursor.
+    ///
+    /// # returns
+    /// * Character index of the cursor.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn cursor_position(&self) -> i32 {
+        unsafe { uiMultilineEntryCursorPosition(self._inner) }
+    }
+
+    /// Moves the multi line entry's text cursor and clears any selection.
+    ///
+    /// # arguments
+    /// * `pos`: Character index of the cursor, `-1` for the end of the text.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_cursor_position(&self, pos: i32) {
+        unsafe { uiMultilineEntrySetCursorPosition(self._inner, pos) }
+    }
+
+    /// Selects a range of the multi line entry's text.
+    ///
+    /// # arguments
+    /// * `start`: Character index where the selection starts.
+    /// * `end`: Character index where the selection ends, `-1` for the end of the text.
+    ///
+    /// # note
+    /// * Passing the same index for `start` and `end` clears the selection.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn select_range(&self, start: i32, end: i32) {
+        unsafe { uiMultilineEntrySelectRange(self._inner, start, end) }
+    }
+
+    /// Returns the selected range of the multi line entry's text.
+    ///
+    /// # returns
+    /// * The character indices `(start, end)` of the selection, `None` if no text is selected.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn selection(&self) -> Option<(i32, i32)> {
+        let (mut start, mut end) = (0, 0);
+        let selected = unsafe { uiMultilineEntrySelection(self._inner, &mut start, &mut end) };
+        if selected == 0 {
+            None
+        } else {
+            Some((start, end))
+        }
+    }
+
+    /// Scrolls the multi line entry to the end of its text.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn scroll_to_end(&self) {
+        unsafe { uiMultilineEntryScrollToEnd(self._inner) }
+    }
+
+    /// Returns whether the multi line entry scrolls to the end when text is appended.
+    ///
+    /// # returns
+    /// * `true` if scrolling automatically, `false` otherwise.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn auto_scroll(&self) -> bool {
+        unsafe { uiMultilineEntryAutoScroll(self._inner) != 0 }
+    }
+
+    /// Sets whether the multi line entry scrolls to the end when text is appended,
+    /// e.g. to keep the latest lines of a log visible.
+    ///
+    /// # arguments
+    /// * `auto_scroll`: `true` to scroll automatically on `append()`, `false` otherwise.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_auto_scroll(&self, auto_scroll: bool) {
+        unsafe { uiMultilineEntrySetAutoScroll(self._inner, auto_scroll as _) }
+    }
+
+    /// Returns the number of lines of the multi line entry's text.
+    /// Lines wrapped for display count as one line.
+    ///
+    /// # returns
+    /// * Number of lines, at least `1`.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn line_count(&self) -> i32 {
+        unsafe { uiMultilineEntryLineCount(self._inner) }
+    }
+
+    /// Returns a line of the multi line entry's text.
+    ///
+    /// # arguments
+    /// * `line`: Index of the line, starting at `0`.
+    ///
+    /// # returns
+    /// * The line without its line break, `None` if `line` is out of range.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn line(&self, line: i32) -> Result<Option<String>, Utf8Error> {
+        let ptr = unsafe { uiMultilineEntryLine(self._inner, line) };
+        if ptr.is_null() {
+            return Ok(None);
+        }
+
+        let text = unsafe { CStr::from_ptr(ptr) }.to_str().map(String::from);
+        unsafe { uiFreeText(ptr) };
+        Ok(Some(text?))
+    }
+
+    /// Returns the color of the text at the start of a line.
+    ///
+    /// # arguments
+    /// * `line`: Index of the line, starting at `0`.
+    ///
+    /// # returns
+    /// * The color passed to `append_with_color()`, `None` for the default text color or if
+    ///   `line` is out of range.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn line_color(&self, line: i32) -> Option<Color> {
+        let (mut r, mut g, mut b, mut a) = (0., 0., 0., 0.);
+        let set =
+            unsafe { uiMultilineEntryLineColor(self._inner, line, &mut r, &mut g, &mut b, &mut a) };
+        (set != 0).then(|| Color::new(r, g, b, a))
+    }
+
     /// Creates a new multi line entry that visually wraps text when lines overflow.
     ///
     /// # returns
@@ -1,7 +1,39 @@
+#[cfg(target_os = "linux")]
+mod progress;
+
 use crate::{
     Control,
     raw::{uiControl, uiNewProgressBar, uiProgressBar, uiProgressBarSetValue, uiProgressBarValue},
 };
+#[cfg(target_os = "linux")]
+pub use progress::Progress;
+#[cfg(target_os = "linux")]
+use {
+    crate::raw::{
+        uiFreeText, uiProgressBarSetShowText, uiProgressBarSetText, uiProgressBarShowText,
+        uiProgressBarText,
+    },
+    std::{
+        ffi::{CStr, CString, NulError},
+        ptr::null,
+        str::Utf8Error,
+    },
+};
+
+/// The state of a progress bar.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum ProgressState {
+    /// A solid bar showing a percentage in the range of `[0, 100]`.
+    Determinate(u8),
+    /// An animated bar for work of unknown length.
+    Indeterminate,
+}
+
+impl Default for ProgressState {
+    fn default() -> Self {
+        Self::Determinate(0)
+    }
+}
 
 pub struct ProgressBar {
     _inner: *mut uiProgressBar,
@@ -14,6 +46,8 @@ impl AsRef<Self> for ProgressBar {
 }
 
 impl Control for ProgressBar {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x50426172);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
@@ -44,6 +78,79 @@ impl ProgressBar {
         unsafe { uiProgressBarSetValue(self._inner, n) }
     }
 
+    /// Returns the progress bar state.
+    ///
+    /// # returns
+    /// * Progress bar state. `[Default Determinate(0)]`
+    pub fn state(&self) -> ProgressState {
+        match self.value() {
+            -1 => ProgressState::Indeterminate,
+            n => ProgressState::Determinate(n as _),
+        }
+    }
+
+    /// Sets the progress bar state.
+    ///
+    /// # arguments
+    /// * `state`: State to set. Percentages above `100` are clamped.
+    pub fn set_state(&self, state: ProgressState) {
+        match state {
+            ProgressState::Determinate(n) => self.set_value(n.min(100) as _),
+            ProgressState::Indeterminate => self.set_value(-1),
+        }
+    }
+
+    /// Returns the text shown on the progress bar.
+    ///
+    /// # returns
+    /// * The text set with `set_text()`, an empty string if none.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn text(&self) -> Result<String, Utf8Error> {
+        let ptr = unsafe { uiProgressBarText(self._inner) };
+        let text = unsafe { CStr::from_ptr(ptr) }.to_str()?.into();
+        unsafe { uiFreeText(ptr) };
+        Ok(text)
+    }
+
+    /// Sets the text shown on the progress bar.
+    ///
+    /// # arguments
+    /// * `text`: Progress bar text, `None` to show the percentage.
+    ///
+    /// # note
+    /// * The text is only shown if enabled with `set_show_text()`.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_text(&self, text: Option<&str>) -> Result<(), NulError> {
+        let text = text.map(CString::new).transpose()?;
+        let ptr = text.as_ref().map_or(null(), |t| t.as_ptr());
+        Ok(unsafe { uiProgressBarSetText(self._inner, ptr) })
+    }
+
+    /// Returns whether text is shown on the progress bar.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn show_text(&self) -> bool {
+        unsafe { uiProgressBarShowText(self._inner) != 0 }
+    }
+
+    /// Sets whether text is shown on the progress bar.
+    ///
+    /// # arguments
+    /// * `show`: `true` to show text, `false` otherwise.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_show_text(&self, show: bool) {
+        unsafe { uiProgressBarSetShowText(self._inner, show as _) }
+    }
+
     /// Creates a new progress bar.
     ///
     /// # returns
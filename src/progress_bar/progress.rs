@@ -0,0 +1,211 @@
+use {
+    super::{ProgressBar, ProgressState},
+    crate::{Button, Control, error::UiError, handle::ControlHandle},
+    std::{
+        sync::{
+            Arc, Mutex, MutexGuard,
+            atomic::{AtomicBool, Ordering},
+        },
+        time::{Duration, Instant},
+    },
+};
+
+struct State {
+    total: u64,
+    done: u64,
+    message: Option<String>,
+    started: Instant,
+    finished: bool,
+}
+
+impl State {
+    fn progress_state(&self) -> ProgressState {
+        match self.total {
+            0 => ProgressState::Indeterminate,
+            total => ProgressState::Determinate((self.done.min(total) * 100 / total) as _),
+        }
+    }
+
+    fn eta(&self) -> Option<Duration> {
+        if self.finished || self.done == 0 || self.total == 0 {
+            return None;
+        }
+        let remaining = self.total.saturating_sub(self.done) as f64 / self.done as f64;
+        Some(self.started.elapsed().mul_f64(remaining))
+    }
+
+    /// E.g. `Exporting – 42%, 1:05 left`.
+    fn text(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(message) = &self.message {
+            parts.push(message.replace('\0', ""));
+        }
+        if let ProgressState::Determinate(percent) = self.progress_state() {
+            let mut percent = format!("{}%", percent);
+            if let Some(eta) = self.eta() {
+                let secs = eta.as_secs();
+                match secs / 3600 {
+                    0 => percent += &format!(", {}:{:02} left", secs / 60, secs % 60),
+                    h => percent += &format!(", {}:{:02}:{:02} left", h, secs / 60 % 60, secs % 60),
+                }
+            }
+            parts.push(percent);
+        }
+        parts.join(" – ")
+    }
+}
+
+struct Shared {
+    state: Mutex<State>,
+    cancelled: AtomicBool,
+}
+
+/// Shows the latest progress on the main thread.
+fn flush(shared: &Shared, bar: ProgressBar) {
+    let Ok(state) = shared.state.lock() else {
+        return;
+    };
+    bar.set_state(state.progress_state());
+    let _ = bar.set_text(Some(&state.text()));
+}
+
+/// Reports the progress of long-running work to a [`ProgressBar`].
+///
+/// Cloned values report to the same bar and can be sent to worker threads.
+/// Updates are shown once the main loop runs, along with the percentage, an optional
+/// message and the estimated time left.
+///
+/// # note
+/// * Only available on Linux for now.
+#[derive(Clone)]
+pub struct Progress {
+    bar: ControlHandle,
+    shared: Arc<Shared>,
+}
+
+impl Progress {
+    fn lock(&self) -> Result<MutexGuard<'_, State>, UiError> {
+        Ok(self.shared.state.lock()?)
+    }
+
+    fn update<F>(&self, f: F) -> Result<(), UiError>
+    where
+        F: FnOnce(&mut State),
+    {
+        f(&mut *self.lock()?);
+        // Updates made until the main loop gets to them are shown in one go.
+        let shared = self.shared.clone();
+        self.bar.queue(move |bar| flush(&shared, bar));
+        Ok(())
+    }
+
+    /// Creates a new progress reporter. Must be called on the main thread.
+    ///
+    /// # arguments
+    /// * `bar`: The progress bar showing the progress. Its text is enabled.
+    /// * `total`: Amount of work, e.g. bytes or items, `0` if unknown.
+    ///
+    /// # note
+    /// * Progress reported after the bar was destroyed is dropped.
+    pub fn new(bar: &ProgressBar, total: u64) -> Self {
+        let state = State {
+            total,
+            done: 0,
+            message: None,
+            started: Instant::now(),
+            finished: false,
+        };
+        bar.set_state(state.progress_state());
+        let _ = bar.set_text(Some(&state.text()));
+        bar.set_show_text(true);
+
+        Self {
+            bar: ControlHandle::new(bar),
+            shared: Arc::new(Shared {
+                state: Mutex::new(state),
+                cancelled: AtomicBool::new(false),
+            }),
+        }
+    }
+
+    /// Sets the amount of work.
+    ///
+    /// # arguments
+    /// * `total`: Amount of work, `0` if unknown to show an animated bar.
+    pub fn set_total(&self, total: u64) -> Result<(), UiError> {
+        self.update(|s| s.total = total)
+    }
+
+    /// Sets the amount of work done.
+    ///
+    /// # arguments
+    /// * `done`: Amount of work done, in the unit of the total.
+    pub fn set(&self, done: u64) -> Result<(), UiError> {
+        self.update(|s| s.done = done)
+    }
+
+    /// Adds to the amount of work done.
+    ///
+    /// # arguments
+    /// * `amount`: Amount of work done since the last report.
+    pub fn advance(&self, amount: u64) -> Result<(), UiError> {
+        self.update(|s| s.done = s.done.saturating_add(amount))
+    }
+
+    /// Sets the message shown before the percentage, e.g. the current file.
+    ///
+    /// # arguments
+    /// * `message`: The message, `None` for none.
+    pub fn set_message(&self, message: Option<&str>) -> Result<(), UiError> {
+        self.update(|s| s.message = message.map(Into::into))
+    }
+
+    /// Marks the work as done, filling the bar and removing the estimated time left.
+    pub fn finish(&self) -> Result<(), UiError> {
+        self.update(|s| {
+            s.done = s.total;
+            s.finished = true;
+        })
+    }
+
+    /// Returns the current state of the progress.
+    pub fn state(&self) -> Result<ProgressState, UiError> {
+        Ok(self.lock()?.progress_state())
+    }
+
+    /// Returns the estimated time left, extrapolated from the progress so far.
+    ///
+    /// # returns
+    /// * The time left, `None` if unknown or finished.
+    pub fn eta(&self) -> Result<Option<Duration>, UiError> {
+        Ok(self.lock()?.eta())
+    }
+
+    /// Requests the work to be cancelled. Workers have to check `is_cancelled()`.
+    pub fn cancel(&self) {
+        self.shared.cancelled.store(true, Ordering::Relaxed)
+    }
+
+    /// Returns whether cancellation was requested.
+    pub fn is_cancelled(&self) -> bool {
+        self.shared.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Makes a button request cancellation when clicked. The button is disabled then.
+    ///
+    /// # arguments
+    /// * `button`: The Cancel button.
+    ///
+    /// # note
+    /// * This replaces the callback registered via `on_clicked()`.
+    pub fn cancel_button(&self, button: &Button) -> Result<(), UiError> {
+        let progress = self.clone();
+        button.on_clicked(
+            move |b, _| {
+                progress.cancel();
+                b.disable();
+            },
+            &mut (),
+        )
+    }
+}
@@ -29,6 +29,8 @@ impl AsRef<Self> for RadioButtons {
 }
 
 impl Control for RadioButtons {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x5264696F);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
@@ -23,6 +23,8 @@ struct uiWindow {
 	int borderless;
 	int resizeable;
 	int focused;
+	void (*onDestroying)(uiWindow *, void *);
+	void *onDestroyingData;
 };
 
 @implementation uiprivNSWindow
@@ -132,6 +134,8 @@ static void uiWindowDestroy(uiControl *c)
 {
 	uiWindow *w = uiWindow(c);
 
+	// let the callback see the window before anything is torn down
+	(*(w->onDestroying))(w, w->onDestroyingData);
 	// hide the window
 	[w->window orderOut:w->window];
 	removeConstraints(w);
@@ -348,6 +352,12 @@ void uiWindowOnClosing(uiWindow *w, int (*f)(uiWindow *, void *), void *data)
 	w->onClosingData = data;
 }
 
+void uiWindowOnDestroying(uiWindow *w, void (*f)(uiWindow *, void *), void *data)
+{
+	w->onDestroying = f;
+	w->onDestroyingData = data;
+}
+
 int uiWindowBorderless(uiWindow *w)
 {
 	return w->borderless;
@@ -431,6 +441,11 @@ static void defaultOnFocusChanged(uiWindow *w, void *data)
 	// do nothing
 }
 
+static void defaultOnDestroying(uiWindow *w, void *data)
+{
+	// do nothing
+}
+
 uiWindow *uiNewWindow(const char *title, int width, int height, int hasMenubar)
 {
 	uiWindow *w;
@@ -449,6 +464,7 @@ uiWindow *uiNewWindow(const char *title, int width, int height, int hasMenubar)
 	uiWindowOnFocusChanged(w, defaultOnFocusChanged, NULL);
 	uiWindowOnContentSizeChanged(w, defaultOnPositionContentSizeChanged, NULL);
 	uiWindowOnPositionChanged(w, defaultOnPositionContentSizeChanged, NULL);
+	uiWindowOnDestroying(w, defaultOnDestroying, NULL);
 
 	return w;
 }
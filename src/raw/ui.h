@@ -268,6 +268,81 @@ _UI_EXTERN void uiControlVerifySetParent(uiControl *c, uiControl *parent);
  */
 _UI_EXTERN int uiControlEnabledToUser(uiControl *c);
 
+/**
+ * Sets the text shown when the mouse pointer rests on the control.
+ *
+ * @param c uiControl instance.
+ * @param tooltip Tooltip text, `NULL` to remove the tooltip.\n
+ *                A valid, `NUL` terminated UTF-8 string.\n
+ *                Data is copied internally. Ownership is not transferred.
+ * @memberof uiControl
+ */
+_UI_EXTERN void uiControlSetTooltip(uiControl *c, const char *tooltip);
+
+/**
+ * Registers a callback for when the control's OS-level widget is destroyed.
+ *
+ * @param c uiControl instance.
+ * @param f Callback function.\n
+ *          @p sender Back reference to the instance that triggered the callback.\n
+ *          @p senderData User data registered with the sender instance.
+ * @param data User data to be passed to the callback.
+ *
+ * @note Unlike most callbacks, every registered callback is called.
+ * @warning The control must not be used from within the callback.
+ * @memberof uiControl
+ */
+_UI_EXTERN void uiControlOnDestroyed(uiControl *c,
+	void (*f)(uiControl *sender, void *senderData), void *data);
+
+/**
+ * Gives keyboard focus to the control.
+ *
+ * @param c uiControl instance.
+ * @note Controls that cannot take focus themselves focus their first focusable child.
+ * @memberof uiControl
+ */
+_UI_EXTERN void uiControlFocus(uiControl *c);
+
+/**
+ * Returns whether the control or one of its children is the focus widget of its window.
+ *
+ * @param c uiControl instance.
+ * @returns `TRUE` if the control has focus, `FALSE` otherwise.
+ * @memberof uiControl
+ */
+_UI_EXTERN int uiControlHasFocus(uiControl *c);
+
+/**
+ * Registers a callback for when the control gains keyboard focus.
+ *
+ * @param c uiControl instance.
+ * @param f Callback function.\n
+ *          @p sender Back reference to the instance that triggered the callback.\n
+ *          @p senderData User data registered with the sender instance.
+ * @param data User data to be passed to the callback.
+ *
+ * @note Only one callback can be registered at a time.
+ * @memberof uiControl
+ */
+_UI_EXTERN void uiControlOnFocusIn(uiControl *c,
+	void (*f)(uiControl *sender, void *senderData), void *data);
+
+/**
+ * Registers a callback for when the control loses keyboard focus.
+ *
+ * @param c uiControl instance.
+ * @param f Callback function.\n
+ *          @p sender Back reference to the instance that triggered the callback.\n
+ *          @p senderData User data registered with the sender instance.
+ * @param data User data to be passed to the callback.
+ *
+ * @note Only one callback can be registered at a time.
+ * @memberof uiControl
+ */
+_UI_EXTERN void uiControlOnFocusOut(uiControl *c,
+	void (*f)(uiControl *sender, void *senderData), void *data);
+
 // TODO Move this to private API? According to old/new.md this should be used by toplevel controls.
 _UI_EXTERN void uiUserBugCannotSetParentOnToplevel(const char *type);
 
@@ -455,6 +530,48 @@ _UI_EXTERN void uiWindowOnFocusChanged(uiWindow *w,
  */
 _UI_EXTERN int uiWindowFocused(uiWindow *w);
 
+/**
+ * Returns the control inside the window that has keyboard focus.
+ *
+ * @param w uiWindow instance.
+ * @returns The focused control, `NULL` if no control has focus.
+ * @memberof uiWindow
+ */
+_UI_EXTERN uiControl *uiWindowFocusedControl(uiWindow *w);
+
+/**
+ * Adds a keyboard shortcut to the window.
+ *
+ * Either @p key or @p extKey identifies the key; @p extKey takes precedence.
+ *
+ * @param w uiWindow instance.
+ * @param modifiers Bitmask of uiModifiers that have to be held.
+ * @param key Unicode code point of the key, `0` if @p extKey is used.
+ * @param extKey uiExtKey of the key, `0` if @p key is used.
+ * @param f Callback function.\n
+ *          @p sender Back reference to the instance that triggered the callback.\n
+ *          @p modifiers, @p key and @p extKey The shortcut as passed when it was added.\n
+ *          @p senderData User data registered with the sender instance.
+ * @param data User data to be passed to the callback.
+ * @note Adding a shortcut that already exists replaces it.
+ * @note Shortcuts also work in windows without a menubar.
+ * @memberof uiWindow
+ */
+_UI_EXTERN void uiWindowAddShortcut(uiWindow *w, int modifiers, uint32_t key, int extKey,
+	void (*f)(uiWindow *sender, int modifiers, uint32_t key, int extKey, void *senderData), void *data);
+
+/**
+ * Removes a keyboard shortcut from the window.
+ *
+ * @param w uiWindow instance.
+ * @param modifiers Bitmask of uiModifiers of the shortcut.
+ * @param key Unicode code point of the key, `0` if @p extKey is used.
+ * @param extKey uiExtKey of the key, `0` if @p key is used.
+ * @note Removing a shortcut that does not exist does nothing.
+ * @memberof uiWindow
+ */
+_UI_EXTERN void uiWindowRemoveShortcut(uiWindow *w, int modifiers, uint32_t key, int extKey);
+
 /**
  * Returns whether or not the window is borderless.
  *
@@ -522,6 +639,155 @@ _UI_EXTERN int uiWindowResizeable(uiWindow *w);
  */
 _UI_EXTERN void uiWindowSetResizeable(uiWindow *w, int resizeable);
 
+/**
+ * Minimizes the window.
+ *
+ * @param w uiWindow instance.
+ * @note The state changes asynchronously; use uiWindowOnStateChanged() to get notified.
+ * @memberof uiWindow
+ */
+_UI_EXTERN void uiWindowMinimize(uiWindow *w);
+
+/**
+ * Returns whether or not the window is minimized.
+ *
+ * @param w uiWindow instance.
+ * @returns `TRUE` if the window is minimized, `FALSE` otherwise. [Default: `FALSE`]
+ * @memberof uiWindow
+ */
+_UI_EXTERN int uiWindowMinimized(uiWindow *w);
+
+/**
+ * Maximizes the window.
+ *
+ * @param w uiWindow instance.
+ * @note The state changes asynchronously; use uiWindowOnStateChanged() to get notified.
+ * @memberof uiWindow
+ */
+_UI_EXTERN void uiWindowMaximize(uiWindow *w);
+
+/**
+ * Returns whether or not the window is maximized.
+ *
+ * @param w uiWindow instance.
+ * @returns `TRUE` if the window is maximized, `FALSE` otherwise. [Default: `FALSE`]
+ * @memberof uiWindow
+ */
+_UI_EXTERN int uiWindowMaximized(uiWindow *w);
+
+/**
+ * Restores a minimized or maximized window to its normal state.
+ *
+ * @param w uiWindow instance.
+ * @note The state changes asynchronously; use uiWindowOnStateChanged() to get notified.
+ * @memberof uiWindow
+ */
+_UI_EXTERN void uiWindowRestore(uiWindow *w);
+
+/**
+ * Returns whether or not the window is kept above other windows.
+ *
+ * @param w uiWindow instance.
+ * @returns `TRUE` if the window is kept above, `FALSE` otherwise. [Default: `FALSE`]
+ * @memberof uiWindow
+ */
+_UI_EXTERN int uiWindowKeepAbove(uiWindow *w);
+
+/**
+ * Sets whether or not the window is kept above other windows.
+ *
+ * @param w uiWindow instance.
+ * @param keepAbove `TRUE` to keep the window above, `FALSE` otherwise.
+ * @note This method is merely a hint and may be ignored by the system.
+ * @memberof uiWindow
+ */
+_UI_EXTERN void uiWindowSetKeepAbove(uiWindow *w, int keepAbove);
+
+/**
+ * Registers a callback for when the window is minimized, maximized, restored,
+ * enters or leaves fullscreen, or changes its keep above state.
+ *
+ * @param w uiWindow instance.
+ * @param f Callback function.\n
+ *          @p sender Back reference to the instance that triggered the callback.\n
+ *          @p senderData User data registered with the sender instance.
+ * @param data User data to be passed to the callback.
+ *
+ * @note Only one callback can be registered at a time.
+ * @memberof uiWindow
+ */
+_UI_EXTERN void uiWindowOnStateChanged(uiWindow *w,
+	void (*f)(uiWindow *sender, void *senderData), void *data);
+
+/**
+ * Asks the window to close, as if the user clicked its close button.
+ *
+ * The callback registered via uiWindowOnClosing() decides whether the window
+ * gets destroyed.
+ *
+ * @param w uiWindow instance.
+ * @warning The window must not be used afterwards if it was destroyed.
+ * @memberof uiWindow
+ */
+_UI_EXTERN void uiWindowClose(uiWindow *w);
+
+/**
+ * Sets the window icon.
+ *
+ * The system picks the representation of @p icon best suited for each place
+ * the icon is shown in, e.g. the title bar or the task switcher.
+ *
+ * @param w uiWindow instance.
+ * @param icon uiImage instance, `NULL` to use the default icon.\n
+ *             Data is copied internally. Ownership is not transferred.
+ * @memberof uiWindow
+ */
+_UI_EXTERN void uiWindowSetIcon(uiWindow *w, struct uiImage *icon);
+
+/**
+ * Sets the minimum size the user can shrink the window content to.
+ *
+ * @param w uiWindow instance.
+ * @param width Minimum window content width, `0` for no limit.
+ * @param height Minimum window content height, `0` for no limit.
+ * @memberof uiWindow
+ */
+_UI_EXTERN void uiWindowSetMinContentSize(uiWindow *w, int width, int height);
+
+/**
+ * Sets the maximum size the user can grow the window content to.
+ *
+ * @param w uiWindow instance.
+ * @param width Maximum window content width, `0` for no limit.
+ * @param height Maximum window content height, `0` for no limit.
+ * @memberof uiWindow
+ */
+_UI_EXTERN void uiWindowSetMaxContentSize(uiWindow *w, int width, int height);
+
+/**
+ * Sets the aspect ratio the user can resize the window to.
+ *
+ * @param w uiWindow instance.
+ * @param width Width part of the ratio, `0` for no fixed ratio.
+ * @param height Height part of the ratio, `0` for no fixed ratio.
+ * @note The ratio applies to the whole window, including the menubar.
+ * @memberof uiWindow
+ */
+_UI_EXTERN void uiWindowSetAspectRatio(uiWindow *w, double width, double height);
+
+/**
+ * Returns the area of a screen not covered by panels, docks or task bars.
+ *
+ * @param w uiWindow instance whose screen is queried, `NULL` for the primary screen.
+ * @param[out] x X position of the work area.
+ * @param[out] y Y position of the work area.
+ * @param[out] width Width of the work area.
+ * @param[out] height Height of the work area.
+ * @note Positions are in the same coordinate space as uiWindowPosition().
+ * @memberof uiWindow @static
+ */
+_UI_EXTERN void uiScreenWorkArea(uiWindow *w, int *x, int *y, int *width, int *height);
+
 /**
  * Creates a new uiWindow.
  *
@@ -536,6 +802,40 @@ _UI_EXTERN void uiWindowSetResizeable(uiWindow *w, int resizeable);
  */
 _UI_EXTERN uiWindow *uiNewWindow(const char *title, int width, int height, int hasMenubar);
 
+/**
+ * Creates a new dialog window.
+ *
+ * The dialog is modal: it stays on top of @p parent and blocks input to all
+ * other windows of the application while it is visible.
+ *
+ * @param parent Parent window.
+ * @param title Window title text.\n
+ *              A valid, `NUL` terminated UTF-8 string.\n
+ *              Data is copied internally. Ownership is not transferred.
+ * @param width Window width.
+ * @param height Window height.
+ * @returns A new uiWindow instance.
+ * @note Unlike regular windows, dialogs are destroyed by default when the user closes them.
+ * @memberof uiWindow @static
+ */
+_UI_EXTERN uiWindow *uiNewDialogWindow(uiWindow *parent, const char *title, int width, int height);
+
+/**
+ * Registers a callback for when the window is about to be destroyed.
+ *
+ * @param w uiWindow instance.
+ * @param f Callback function.\n
+ *          @p sender Back reference to the instance that triggered the callback.\n
+ *          @p senderData User data registered with the sender instance.
+ * @param data User data to be passed to the callback.
+ *
+ * @note Only one callback can be registered at a time.
+ * @note The window must not be used after the callback returns.
+ * @memberof uiWindow
+ */
+_UI_EXTERN void uiWindowOnDestroying(uiWindow *w,
+	void (*f)(uiWindow *sender, void *senderData), void *data);
+
 
 /**
  * A control that visually represents a button to be clicked by the user to trigger an action.
@@ -584,6 +884,15 @@ _UI_EXTERN void uiButtonSetText(uiButton *b, const char *text);
 _UI_EXTERN void uiButtonOnClicked(uiButton *b,
 	void (*f)(uiButton *sender, void *senderData), void *data);
 
+/**
+ * Clicks the button as if the user did, calling the registered callback.
+ *
+ * @param b uiButton instance.
+ * @note Disabled buttons are clicked as well.
+ * @memberof uiButton
+ */
+_UI_EXTERN void uiButtonClick(uiButton *b);
+
 /**
  * Creates a new button.
  *
@@ -828,6 +1137,102 @@ _UI_EXTERN int uiEntryReadOnly(uiEntry *e);
  */
 _UI_EXTERN void uiEntrySetReadOnly(uiEntry *e, int readonly);
 
+/**
+ * Registers a callback for when the user presses Enter in the entry.
+ *
+ * @param e uiEntry instance.
+ * @param f Callback function.\n
+ *          @p sender Back reference to the instance that triggered the callback.\n
+ *          @p senderData User data registered with the sender instance.
+ * @param data User data to be passed to the callback.
+ *
+ * @note Only one callback can be registered at a time.
+ * @memberof uiEntry
+ */
+_UI_EXTERN void uiEntryOnActivate(uiEntry *e,
+	void (*f)(uiEntry *sender, void *senderData), void *data);
+
+/**
+ * Sets the text shown in the empty entry as a hint for the expected input.
+ *
+ * @param e uiEntry instance.
+ * @param text Placeholder text, `NULL` to remove the placeholder.\n
+ *             A valid, `NUL` terminated UTF-8 string.\n
+ *             Data is copied internally. Ownership is not transferred.
+ * @memberof uiEntry
+ */
+_UI_EXTERN void uiEntrySetPlaceholder(uiEntry *e, const char *text);
+
+/**
+ * Returns the maximum number of characters the entry accepts.
+ *
+ * @param e uiEntry instance.
+ * @returns Maximum number of characters, `0` if unlimited. [Default: `0`]
+ * @memberof uiEntry
+ */
+_UI_EXTERN int uiEntryMaxLength(uiEntry *e);
+
+/**
+ * Sets the maximum number of characters the entry accepts.
+ *
+ * @param e uiEntry instance.
+ * @param length Maximum number of characters, `0` for unlimited.
+ * @note Text already exceeding the limit is truncated.
+ * @memberof uiEntry
+ */
+_UI_EXTERN void uiEntrySetMaxLength(uiEntry *e, int length);
+
+/**
+ * Returns whether the entry's text is shown or masked.
+ *
+ * @param e uiEntry instance.
+ * @returns `TRUE` if the text is shown, `FALSE` if masked.\n
+ *          [Default: `FALSE` for password entries, `TRUE` otherwise]
+ * @memberof uiEntry
+ */
+_UI_EXTERN int uiEntryVisibility(uiEntry *e);
+
+/**
+ * Sets whether the entry's text is shown or masked.
+ *
+ * @param e uiEntry instance.
+ * @param visible `TRUE` to show the text, `FALSE` to mask it.
+ * @memberof uiEntry
+ */
+_UI_EXTERN void uiEntrySetVisibility(uiEntry *e, int visible);
+
+/**
+ * Selects a range of the entry's text.
+ *
+ * @param e uiEntry instance.
+ * @param start Character index where the selection starts.
+ * @param end Character index where the selection ends, `-1` for the end of the text.
+ * @note Passing the same index for @p start and @p end clears the selection.
+ * @memberof uiEntry
+ */
+_UI_EXTERN void uiEntrySelectRange(uiEntry *e, int start, int end);
+
+/**
+ * Returns the selected range of the entry's text.
+ *
+ * @param e uiEntry instance.
+ * @param[out] start Character index where the selection starts.
+ * @param[out] end Character index where the selection ends.
+ * @returns `TRUE` if text is selected, `FALSE` otherwise.
+ * @note Without a selection, both @p start and @p end are set to the cursor position.
+ * @memberof uiEntry
+ */
+_UI_EXTERN int uiEntrySelection(uiEntry *e, int *start, int *end);
+
+/**
+ * Returns the position of the entry's text cursor.
+ *
+ * @param e uiEntry instance.
+ * @returns Character index of the cursor.
+ * @memberof uiEntry
+ */
+_UI_EXTERN int uiEntryCursorPosition(uiEntry *e);
+
 /**
  * Creates a new entry.
  *
@@ -890,6 +1295,126 @@ _UI_EXTERN char *uiLabelText(uiLabel *l);
  */
 _UI_EXTERN void uiLabelSetText(uiLabel *l, const char *text);
 
+/**
+ * Sets the label text from Pango-like markup, e.g. `<b>bold</b>`.
+ *
+ * @param l uiLabel instance.
+ * @param markup Label markup.\n
+ *               `&`, `<` and `>` in plain text must be escaped as `&amp;`, `&lt;` and `&gt;`.\n
+ *               A valid, `NUL` terminated UTF-8 string.\n
+ *               Data is copied internally. Ownership is not transferred.
+ * @returns `TRUE` on success, `FALSE` if the markup is invalid. The label is left unchanged then.
+ * @note uiLabelText() returns the text without markup.
+ * @memberof uiLabel
+ */
+_UI_EXTERN int uiLabelSetMarkup(uiLabel *l, const char *markup);
+
+/**
+ * Returns the horizontal alignment of the label text.
+ *
+ * @param l uiLabel instance.
+ * @returns A uiDrawTextAlign value. [Default: `uiDrawTextAlignLeft`]
+ * @memberof uiLabel
+ */
+_UI_EXTERN int uiLabelTextAlign(uiLabel *l);
+
+/**
+ * Sets the horizontal alignment of the label text.
+ *
+ * @param l uiLabel instance.
+ * @param align A uiDrawTextAlign value.\n
+ *              Applies to the text as a whole as well as to each line of wrapped text.
+ * @memberof uiLabel
+ */
+_UI_EXTERN void uiLabelSetTextAlign(uiLabel *l, int align);
+
+/**
+ * Returns whether the label text is wrapped.
+ *
+ * @param l uiLabel instance.
+ * @returns `TRUE` if wrapped, `FALSE` otherwise. [Default: `FALSE`]
+ * @memberof uiLabel
+ */
+_UI_EXTERN int uiLabelWrap(uiLabel *l);
+
+/**
+ * Sets whether the label text is wrapped at word boundaries.
+ *
+ * @param l uiLabel instance.
+ * @param wrap `TRUE` to wrap, `FALSE` to keep the text on one line per line break.
+ * @note Words longer than a line are broken between characters.
+ * @memberof uiLabel
+ */
+_UI_EXTERN void uiLabelSetWrap(uiLabel *l, int wrap);
+
+/**
+ * Returns the desired maximum width of the label.
+ *
+ * @param l uiLabel instance.
+ * @returns Maximum width in characters, `-1` if unset. [Default: `-1`]
+ * @memberof uiLabel
+ */
+_UI_EXTERN int uiLabelMaxWidth(uiLabel *l);
+
+/**
+ * Sets the desired maximum width of the label.
+ *
+ * @param l uiLabel instance.
+ * @param chars Maximum width in characters, `-1` to unset.
+ * @note Only has an effect if the label is wrapped or ellipsized.
+ * @memberof uiLabel
+ */
+_UI_EXTERN void uiLabelSetMaxWidth(uiLabel *l, int chars);
+
+/**
+ * Where to shorten text that does not fit.
+ *
+ * @enum uiLabelEllipsize
+ */
+_UI_ENUM(uiLabelEllipsize) {
+	uiLabelEllipsizeNone,	//!< Never shorten the text.
+	uiLabelEllipsizeStart,	//!< Omit characters at the start.
+	uiLabelEllipsizeMiddle,	//!< Omit characters in the middle, e.g. for file paths.
+	uiLabelEllipsizeEnd,	//!< Omit characters at the end.
+};
+
+/**
+ * Returns where the label text is shortened if it does not fit.
+ *
+ * @param l uiLabel instance.
+ * @returns The ellipsize mode. [Default: `uiLabelEllipsizeNone`]
+ * @memberof uiLabel
+ */
+_UI_EXTERN uiLabelEllipsize uiLabelEllipsizeMode(uiLabel *l);
+
+/**
+ * Sets where the label text is shortened if it does not fit.
+ *
+ * @param l uiLabel instance.
+ * @param mode The ellipsize mode.
+ * @note An ellipsized label no longer requests the width of its whole text.
+ * @memberof uiLabel
+ */
+_UI_EXTERN void uiLabelSetEllipsizeMode(uiLabel *l, uiLabelEllipsize mode);
+
+/**
+ * Returns whether the user can select the label text.
+ *
+ * @param l uiLabel instance.
+ * @returns `TRUE` if selectable, `FALSE` otherwise. [Default: `FALSE`]
+ * @memberof uiLabel
+ */
+_UI_EXTERN int uiLabelSelectable(uiLabel *l);
+
+/**
+ * Sets whether the user can select and copy the label text.
+ *
+ * @param l uiLabel instance.
+ * @param selectable `TRUE` to make the text selectable, `FALSE` otherwise.
+ * @memberof uiLabel
+ */
+_UI_EXTERN void uiLabelSetSelectable(uiLabel *l, int selectable);
+
 /**
  * Creates a new label.
  *
@@ -1172,7 +1697,112 @@ _UI_EXTERN void uiSpinboxOnChanged(uiSpinbox *s,
  * @todo complain or disallow min>max?
  * @memberof uiSpinbox @static
  */
-_UI_EXTERN uiSpinbox *uiNewSpinbox(int min, int max);
+_UI_EXTERN uiSpinbox *uiNewSpinbox(int min, int max);
+
+/**
+ * Creates a new spinbox with fractional values.
+ *
+ * The initial spinbox value equals the minimum value.
+ * @p min and @p max are swapped if `min>max`.
+ *
+ * @param min Minimum value.
+ * @param max Maximum value.
+ * @param step Step increment of the arrows. Must be greater than `0`.\n
+ *             The precision is derived from it, e.g. `2` decimals for `0.01`.
+ * @returns A new uiSpinbox instance.
+ * @memberof uiSpinbox @static
+ */
+_UI_EXTERN uiSpinbox *uiNewSpinboxDouble(double min, double max, double step);
+
+/**
+ * Returns the spinbox value as fractional number.
+ *
+ * @param s uiSpinbox instance.
+ * @returns Spinbox value.
+ * @memberof uiSpinbox
+ */
+_UI_EXTERN double uiSpinboxValueDouble(uiSpinbox *s);
+
+/**
+ * Sets the spinbox value from a fractional number.
+ *
+ * @param s uiSpinbox instance.
+ * @param value Value to set.
+ * @note Setting a value out of range will clamp to the nearest value in range.
+ * @note The value is rounded to the precision of the spinbox.
+ * @memberof uiSpinbox
+ */
+_UI_EXTERN void uiSpinboxSetValueDouble(uiSpinbox *s, double value);
+
+/**
+ * Sets the spinbox range.
+ *
+ * @param s uiSpinbox instance.
+ * @param min Minimum value.
+ * @param max Maximum value.
+ * @note The value is clamped to the new range without triggering uiSpinboxOnChanged().
+ * @note @p min and @p max are swapped if `min>max`.
+ * @memberof uiSpinbox
+ */
+_UI_EXTERN void uiSpinboxSetRange(uiSpinbox *s, int min, int max);
+
+/**
+ * Sets the spinbox range from fractional numbers.
+ *
+ * @param s uiSpinbox instance.
+ * @param min Minimum value.
+ * @param max Maximum value.
+ * @note The value is clamped to the new range without triggering uiSpinboxOnChanged().
+ * @note @p min and @p max are swapped if `min>max`.
+ * @memberof uiSpinbox
+ */
+_UI_EXTERN void uiSpinboxSetRangeDouble(uiSpinbox *s, double min, double max);
+
+/**
+ * Returns the step increment of the spinbox arrows and arrow keys.
+ *
+ * @param s uiSpinbox instance.
+ * @returns Step increment. [Default: `1` for integer spinboxes]
+ * @memberof uiSpinbox
+ */
+_UI_EXTERN double uiSpinboxStep(uiSpinbox *s);
+
+/**
+ * Returns the page increment of the Page Up and Page Down keys.
+ *
+ * @param s uiSpinbox instance.
+ * @returns Page increment. [Default: `10` times the step]
+ * @memberof uiSpinbox
+ */
+_UI_EXTERN double uiSpinboxPage(uiSpinbox *s);
+
+/**
+ * Sets the step and page increments of the spinbox.
+ *
+ * @param s uiSpinbox instance.
+ * @param step Step increment of the arrows and arrow keys.
+ * @param page Page increment of the Page Up and Page Down keys.
+ * @memberof uiSpinbox
+ */
+_UI_EXTERN void uiSpinboxSetIncrements(uiSpinbox *s, double step, double page);
+
+/**
+ * Returns the number of decimals the spinbox shows.
+ *
+ * @param s uiSpinbox instance.
+ * @returns Number of decimals. [Default: `0` for integer spinboxes]
+ * @memberof uiSpinbox
+ */
+_UI_EXTERN int uiSpinboxPrecision(uiSpinbox *s);
+
+/**
+ * Sets the number of decimals the spinbox shows.
+ *
+ * @param s uiSpinbox instance.
+ * @param digits Number of decimals, at most `20`.
+ * @memberof uiSpinbox
+ */
+_UI_EXTERN void uiSpinboxSetPrecision(uiSpinbox *s, int digits);
 
 
 /**
@@ -1287,6 +1917,138 @@ _UI_EXTERN void uiSliderSetRange(uiSlider *s, int min, int max);
  */
 _UI_EXTERN uiSlider *uiNewSlider(int min, int max);
 
+/**
+ * Creates a new slider with fractional values.
+ *
+ * The initial slider value equals the minimum value.
+ * @p min and @p max are swapped if `min>max`.
+ *
+ * @param min Minimum value.
+ * @param max Maximum value.
+ * @param step Step increment of the arrow keys. Must be greater than `0`.\n
+ *             The precision is derived from it, e.g. `2` decimals for `0.01`.
+ * @returns A new uiSlider instance.
+ * @memberof uiSlider @static
+ */
+_UI_EXTERN uiSlider *uiNewSliderDouble(double min, double max, double step);
+
+/**
+ * Returns the slider value as fractional number.
+ *
+ * @param s uiSlider instance.
+ * @returns Slider value.
+ * @memberof uiSlider
+ */
+_UI_EXTERN double uiSliderValueDouble(uiSlider *s);
+
+/**
+ * Sets the slider value from a fractional number.
+ *
+ * @param s uiSlider instance.
+ * @param value Value to set.
+ * @note Setting a value out of range will clamp to the nearest value in range.
+ * @memberof uiSlider
+ */
+_UI_EXTERN void uiSliderSetValueDouble(uiSlider *s, double value);
+
+/**
+ * Sets the slider range from fractional numbers.
+ *
+ * @param s uiSlider instance.
+ * @param min Minimum value.
+ * @param max Maximum value.
+ * @note @p min and @p max are swapped if `min>max`.
+ * @memberof uiSlider
+ */
+_UI_EXTERN void uiSliderSetRangeDouble(uiSlider *s, double min, double max);
+
+/**
+ * Returns the step increment of the arrow keys.
+ *
+ * @param s uiSlider instance.
+ * @returns Step increment. [Default: `1` for integer sliders]
+ * @memberof uiSlider
+ */
+_UI_EXTERN double uiSliderStep(uiSlider *s);
+
+/**
+ * Returns the page increment of the Page Up and Page Down keys.
+ *
+ * @param s uiSlider instance.
+ * @returns Page increment. [Default: `10` times the step]
+ * @memberof uiSlider
+ */
+_UI_EXTERN double uiSliderPage(uiSlider *s);
+
+/**
+ * Sets the step and page increments of the slider.
+ *
+ * @param s uiSlider instance.
+ * @param step Step increment of the arrow keys.
+ * @param page Page increment of the Page Up and Page Down keys.
+ * @memberof uiSlider
+ */
+_UI_EXTERN void uiSliderSetIncrements(uiSlider *s, double step, double page);
+
+/**
+ * Returns the number of decimals the slider rounds its value to.
+ *
+ * @param s uiSlider instance.
+ * @returns Number of decimals. [Default: `0` for integer sliders]
+ * @memberof uiSlider
+ */
+_UI_EXTERN int uiSliderPrecision(uiSlider *s);
+
+/**
+ * Sets the number of decimals the slider rounds its value to.
+ *
+ * @param s uiSlider instance.
+ * @param digits Number of decimals.
+ * @note The tool tip shows the value with as many decimals.
+ * @memberof uiSlider
+ */
+_UI_EXTERN void uiSliderSetPrecision(uiSlider *s, int digits);
+
+/**
+ * Returns whether the slider is vertical.
+ *
+ * @param s uiSlider instance.
+ * @returns `TRUE` if vertical, `FALSE` if horizontal. [Default: `FALSE`]
+ * @memberof uiSlider
+ */
+_UI_EXTERN int uiSliderVertical(uiSlider *s);
+
+/**
+ * Sets whether the slider is vertical.
+ *
+ * @param s uiSlider instance.
+ * @param vertical `TRUE` for a vertical slider, `FALSE` for a horizontal one.
+ * @note Vertical sliders have their maximum at the top.
+ * @memberof uiSlider
+ */
+_UI_EXTERN void uiSliderSetVertical(uiSlider *s, int vertical);
+
+/**
+ * Adds a tick mark to the slider.
+ *
+ * @param s uiSlider instance.
+ * @param value Value to mark.
+ * @param label Text shown next to the mark, `NULL` for none.\n
+ *              A valid, `NUL` terminated UTF-8 string.\n
+ *              Data is copied internally. Ownership is not transferred.
+ * @note Dragging the slider snaps to nearby marks.
+ * @memberof uiSlider
+ */
+_UI_EXTERN void uiSliderAddTick(uiSlider *s, double value, const char *label);
+
+/**
+ * Removes all tick marks from the slider.
+ *
+ * @param s uiSlider instance.
+ * @memberof uiSlider
+ */
+_UI_EXTERN void uiSliderClearTicks(uiSlider *s);
+
 
 /**
  * A control that visualizes the progress of a task via the fill level of a horizontal bar.
@@ -1324,6 +2086,47 @@ _UI_EXTERN int uiProgressBarValue(uiProgressBar *p);
  */
 _UI_EXTERN void uiProgressBarSetValue(uiProgressBar *p, int n);
 
+/**
+ * Returns the text shown on the progress bar.
+ *
+ * @param p uiProgressBar instance.
+ * @returns The text set with uiProgressBarSetText(), an empty string if none.\n
+ *          A `NUL` terminated UTF-8 string.\n
+ *          Caller is responsible for freeing the data with `uiFreeText()`.
+ * @memberof uiProgressBar
+ */
+_UI_EXTERN char *uiProgressBarText(uiProgressBar *p);
+
+/**
+ * Sets the text shown on the progress bar.
+ *
+ * @param p uiProgressBar instance.
+ * @param text Progress bar text, `NULL` to show the percentage.\n
+ *             A valid, `NUL` terminated UTF-8 string.\n
+ *             Data is copied internally. Ownership is not transferred.
+ * @note The text is only shown if enabled with uiProgressBarSetShowText().
+ * @memberof uiProgressBar
+ */
+_UI_EXTERN void uiProgressBarSetText(uiProgressBar *p, const char *text);
+
+/**
+ * Returns whether text is shown on the progress bar.
+ *
+ * @param p uiProgressBar instance.
+ * @returns `TRUE` if text is shown, `FALSE` otherwise. [Default: `FALSE`]
+ * @memberof uiProgressBar
+ */
+_UI_EXTERN int uiProgressBarShowText(uiProgressBar *p);
+
+/**
+ * Sets whether text is shown on the progress bar.
+ *
+ * @param p uiProgressBar instance.
+ * @param show `TRUE` to show text, `FALSE` otherwise.
+ * @memberof uiProgressBar
+ */
+_UI_EXTERN void uiProgressBarSetShowText(uiProgressBar *p, int show);
+
 /**
  * Creates a new progress bar.
  *
@@ -1744,8 +2547,8 @@ _UI_EXTERN void uiMultilineEntryAppend(uiMultilineEntry *e, const char *text);
  *          @p senderData User data registered with the sender instance.\n
  * @param data User data to be passed to the callback.
  *
- * @note The callback is not triggered when calling uiMultilineEntrySetText()
- *       or uiMultilineEntryAppend().
+ * @note The callback is not triggered when calling uiMultilineEntrySetText(),
+ *       uiMultilineEntryAppend() or uiMultilineEntryInsertAtCursor().
  * @note Only one callback can be registered at a time.
  * @memberof uiMultilineEntry
  */
@@ -1770,6 +2573,151 @@ _UI_EXTERN int uiMultilineEntryReadOnly(uiMultilineEntry *e);
  */
 _UI_EXTERN void uiMultilineEntrySetReadOnly(uiMultilineEntry *e, int readonly);
 
+/**
+ * Inserts text at the multi line entry's text cursor.
+ *
+ * @param e uiMultilineEntry instance.
+ * @param text Text to insert.\n
+ *             A valid, `NUL` terminated UTF-8 string.\n
+ *             Data is copied internally. Ownership is not transferred.
+ * @memberof uiMultilineEntry
+ */
+_UI_EXTERN void uiMultilineEntryInsertAtCursor(uiMultilineEntry *e, const char *text);
+
+/**
+ * Appends text in a color to the multi line entry's text.
+ *
+ * @param e uiMultilineEntry instance.
+ * @param text Text to append.\n
+ *             A valid, `NUL` terminated UTF-8 string.\n
+ *             Data is copied internally. Ownership is not transferred.
+ * @param r Red. Double in range of [0, 1.0].
+ * @param g Green. Double in range of [0, 1.0].
+ * @param b Blue. Double in range of [0, 1.0].
+ * @param a Alpha. Double in range of [0, 1.0].
+ * @note Scrolls to the end like uiMultilineEntryAppend() if uiMultilineEntryAutoScroll() is set.
+ * @memberof uiMultilineEntry
+ */
+_UI_EXTERN void uiMultilineEntryAppendWithColor(uiMultilineEntry *e, const char *text,
+	double r, double g, double b, double a);
+
+/**
+ * Deletes whole lines of the multi line entry's text.
+ *
+ * @param e uiMultilineEntry instance.
+ * @param start Index of the first line to delete, starting at `0`.
+ * @param count Number of lines to delete, including their line breaks.
+ * @note Lines past the last line are ignored.
+ * @memberof uiMultilineEntry
+ */
+_UI_EXTERN void uiMultilineEntryDeleteLines(uiMultilineEntry *e, int start, int count);
+
+/**
+ * Returns the color of the text at the start of a line.
+ *
+ * @param e uiMultilineEntry instance.
+ * @param line Index of the line, starting at `0`.
+ * @param[out] r Red. Double in range of [0, 1.0].
+ * @param[out] g Green. Double in range of [0, 1.0].
+ * @param[out] b Blue. Double in range of [0, 1.0].
+ * @param[out] a Alpha. Double in range of [0, 1.0].
+ * @returns `TRUE` if the text was appended with a color, `FALSE` otherwise.
+ * @note The colors are left untouched if `FALSE` is returned.
+ * @memberof uiMultilineEntry
+ */
+_UI_EXTERN int uiMultilineEntryLineColor(uiMultilineEntry *e, int line,
+	double *r, double *g, double *b, double *a);
+
+/**
+ * Returns the position of the multi line entry's text cursor.
+ *
+ * @param e uiMultilineEntry instance.
+ * @returns Character index of the cursor.
+ * @memberof uiMultilineEntry
+ */
+_UI_EXTERN int uiMultilineEntryCursorPosition(uiMultilineEntry *e);
+
+/**
+ * Moves the multi line entry's text cursor.
+ *
+ * @param e uiMultilineEntry instance.
+ * @param pos Character index of the cursor, `-1` for the end of the text.
+ * @note Any selection is cleared.
+ * @memberof uiMultilineEntry
+ */
+_UI_EXTERN void uiMultilineEntrySetCursorPosition(uiMultilineEntry *e, int pos);
+
+/**
+ * Selects a range of the multi line entry's text.
+ *
+ * @param e uiMultilineEntry instance.
+ * @param start Character index where the selection starts.
+ * @param end Character index where the selection ends, `-1` for the end of the text.
+ * @note Passing the same index for @p start and @p end clears the selection.
+ * @memberof uiMultilineEntry
+ */
+_UI_EXTERN void uiMultilineEntrySelectRange(uiMultilineEntry *e, int start, int end);
+
+/**
+ * Returns the selected range of the multi line entry's text.
+ *
+ * @param e uiMultilineEntry instance.
+ * @param[out] start Character index where the selection starts.
+ * @param[out] end Character index where the selection ends.
+ * @returns `TRUE` if text is selected, `FALSE` otherwise.
+ * @note Without a selection, both @p start and @p end are set to the cursor position.
+ * @memberof uiMultilineEntry
+ */
+_UI_EXTERN int uiMultilineEntrySelection(uiMultilineEntry *e, int *start, int *end);
+
+/**
+ * Scrolls the multi line entry to the end of its text.
+ *
+ * @param e uiMultilineEntry instance.
+ * @memberof uiMultilineEntry
+ */
+_UI_EXTERN void uiMultilineEntryScrollToEnd(uiMultilineEntry *e);
+
+/**
+ * Returns whether the multi line entry scrolls to the end when text is appended.
+ *
+ * @param e uiMultilineEntry instance.
+ * @returns `TRUE` if scrolling automatically, `FALSE` otherwise. [Default: `FALSE`]
+ * @memberof uiMultilineEntry
+ */
+_UI_EXTERN int uiMultilineEntryAutoScroll(uiMultilineEntry *e);
+
+/**
+ * Sets whether the multi line entry scrolls to the end when text is appended.
+ *
+ * @param e uiMultilineEntry instance.
+ * @param autoScroll `TRUE` to scroll automatically on uiMultilineEntryAppend(), `FALSE` otherwise.
+ * @memberof uiMultilineEntry
+ */
+_UI_EXTERN void uiMultilineEntrySetAutoScroll(uiMultilineEntry *e, int autoScroll);
+
+/**
+ * Returns the number of lines of the multi line entry's text.
+ *
+ * @param e uiMultilineEntry instance.
+ * @returns Number of lines, at least `1`.
+ * @note Lines wrapped for display count as one line.
+ * @memberof uiMultilineEntry
+ */
+_UI_EXTERN int uiMultilineEntryLineCount(uiMultilineEntry *e);
+
+/**
+ * Returns a line of the multi line entry's text.
+ *
+ * @param e uiMultilineEntry instance.
+ * @param line Index of the line, starting at `0`.
+ * @returns The line without its line break, `NULL` if @p line is out of range.\n
+ *          A `NUL` terminated UTF-8 string.\n
+ *          Caller is responsible for freeing the data with `uiFreeText()`.
+ * @memberof uiMultilineEntry
+ */
+_UI_EXTERN char *uiMultilineEntryLine(uiMultilineEntry *e, int line);
+
 /**
  * Creates a new multi line entry that visually wraps text when lines overflow.
  *
@@ -1829,30 +2777,78 @@ _UI_EXTERN void uiMenuItemDisable(uiMenuItem *m);
  * @note Only one callback can be registered at a time.
  * @memberof uiMenuItem
  */
-_UI_EXTERN void uiMenuItemOnClicked(uiMenuItem *m,
-	void (*f)(uiMenuItem *sender, uiWindow *window, void *senderData), void *data);
+_UI_EXTERN void uiMenuItemOnClicked(uiMenuItem *m,
+	void (*f)(uiMenuItem *sender, uiWindow *window, void *senderData), void *data);
+
+/**
+ * Returns whether or not the menu item's checkbox is checked.
+ *
+ * To be used only with items created via uiMenuAppendCheckItem().
+ *
+ * @param m uiMenuItem instance.
+ * @returns `TRUE` if checked, `FALSE` otherwise. [Default: `FALSE`]
+ * @memberof uiMenuItem
+ */
+_UI_EXTERN int uiMenuItemChecked(uiMenuItem *m);
+
+/**
+ * Sets whether or not the menu item's checkbox is checked.
+ *
+ * To be used only with items created via uiMenuAppendCheckItem().
+ *
+ * @param m uiMenuItem instance.
+ * @param checked `TRUE` to check menu item checkbox, `FALSE` otherwise.
+ * @memberof uiMenuItem
+ */
+_UI_EXTERN void uiMenuItemSetChecked(uiMenuItem *m, int checked);
+
+/**
+ * Returns the menu item text.
+ *
+ * @param m uiMenuItem instance.
+ * @returns The text of the menu item.\n
+ *          A `NUL` terminated UTF-8 string.\n
+ *          Caller is responsible for freeing the data with `uiFreeText()`.
+ * @memberof uiMenuItem
+ */
+_UI_EXTERN char *uiMenuItemText(uiMenuItem *m);
+
+/**
+ * Sets the menu item text.
+ *
+ * @param m uiMenuItem instance.
+ * @param text Menu item text.\n
+ *             A `NUL` terminated UTF-8 string.\n
+ *             Data is copied internally. Ownership is not transferred.
+ * @note Menu items already shown in a window are updated as well.
+ * @memberof uiMenuItem
+ */
+_UI_EXTERN void uiMenuItemSetText(uiMenuItem *m, const char *text);
 
 /**
- * Returns whether or not the menu item's checkbox is checked.
+ * Sets the keyboard shortcut activating the menu item.
  *
- * To be used only with items created via uiMenuAppendCheckItem().
+ * Either @p key or @p extKey identifies the key; @p extKey takes precedence.
  *
  * @param m uiMenuItem instance.
- * @returns `TRUE` if checked, `FALSE` otherwise. [Default: `FALSE`]
+ * @param modifiers Bitmask of uiModifiers that have to be held.
+ * @param key Unicode code point of the key, `0` if @p extKey is used.
+ * @param extKey uiExtKey of the key, `0` if @p key is used.
+ * @note Pass `0` for both @p key and @p extKey to remove the accelerator.
+ * @warning Separators and submenus cannot have accelerators.
  * @memberof uiMenuItem
  */
-_UI_EXTERN int uiMenuItemChecked(uiMenuItem *m);
+_UI_EXTERN void uiMenuItemSetAccelerator(uiMenuItem *m, int modifiers, uint32_t key, int extKey);
 
 /**
- * Sets whether or not the menu item's checkbox is checked.
- *
- * To be used only with items created via uiMenuAppendCheckItem().
+ * Removes the menu item from its menu and frees it.
  *
  * @param m uiMenuItem instance.
- * @param checked `TRUE` to check menu item checkbox, `FALSE` otherwise.
+ * @note Menu items already shown in a window are removed as well.
+ * @warning The menu item must not be used afterwards.
  * @memberof uiMenuItem
  */
-_UI_EXTERN void uiMenuItemSetChecked(uiMenuItem *m, int checked);
+_UI_EXTERN void uiMenuItemDelete(uiMenuItem *m);
 
 /**
  * An application level menu bar.
@@ -1947,6 +2943,28 @@ _UI_EXTERN uiMenuItem *uiMenuAppendAboutItem(uiMenu *m);
  */
 _UI_EXTERN void uiMenuAppendSeparator(uiMenu *m);
 
+/**
+ * Appends a new submenu.
+ *
+ * @param m uiMenu instance.
+ * @param name Submenu label.\n
+ *             A `NUL` terminated UTF-8 string.\n
+ *             Data is copied internally. Ownership is not transferred.
+ * @returns A new uiMenu instance.
+ * @memberof uiMenu
+ */
+_UI_EXTERN uiMenu *uiMenuAppendSubmenu(uiMenu *m, const char *name);
+
+/**
+ * Removes a submenu from its parent menu and frees it.
+ *
+ * @param m uiMenu instance created via uiMenuAppendSubmenu().
+ * @warning Top-level menus cannot be deleted.
+ * @warning The menu and its items must not be used afterwards.
+ * @memberof uiMenu
+ */
+_UI_EXTERN void uiMenuDelete(uiMenu *m);
+
 /**
  * Creates a new menu.
  *
@@ -1960,6 +2978,118 @@ _UI_EXTERN void uiMenuAppendSeparator(uiMenu *m);
  */
 _UI_EXTERN uiMenu *uiNewMenu(const char *name);
 
+/**
+ * Creates a new popup menu.
+ *
+ * Popup menus are not part of the menu bar. They are filled with the same
+ * uiMenuAppend*() functions as regular menus and can be created at any time.
+ *
+ * @returns A new uiMenu instance.
+ * @warning `Quit`, `Preferences` and `About` items cannot be added to popup menus.
+ * @memberof uiMenu @static
+ */
+_UI_EXTERN uiMenu *uiNewPopupMenu(void);
+
+/**
+ * Shows a popup menu at the mouse pointer.
+ *
+ * @param m uiMenu instance created via uiNewPopupMenu().
+ * @param c The control the menu is shown for.
+ * @returns `TRUE` if the menu was shown, `FALSE` if @p c is not inside a window.
+ * @note The window passed to menu item callbacks is the window containing @p c.
+ * @memberof uiMenu
+ */
+_UI_EXTERN int uiMenuPopup(uiMenu *m, uiControl *c);
+
+/**
+ * Shows a popup menu at a position relative to a control.
+ *
+ * @param m uiMenu instance created via uiNewPopupMenu().
+ * @param c The control the menu is shown for.
+ * @param x X position relative to the top left corner of @p c.
+ * @param y Y position relative to the top left corner of @p c.
+ * @returns `TRUE` if the menu was shown, `FALSE` if @p c is not inside a window.
+ * @note The window passed to menu item callbacks is the window containing @p c.
+ * @memberof uiMenu
+ */
+_UI_EXTERN int uiMenuPopupAt(uiMenu *m, uiControl *c, int x, int y);
+
+/**
+ * Sets the popup menu shown when the control is right-clicked.
+ *
+ * The menu is also shown when the platform's context menu key is pressed
+ * while the control has keyboard focus.
+ *
+ * @param c uiControl instance.
+ * @param m uiMenu instance created via uiNewPopupMenu(), `NULL` to remove the context menu.
+ * @memberof uiControl
+ */
+_UI_EXTERN void uiControlSetContextMenu(uiControl *c, uiMenu *m);
+
+typedef struct uiDropData uiDropData;
+
+/**
+ * Data dropped onto a control.
+ *
+ * Either @p Paths or @p Text holds the data.
+ *
+ * @struct uiDropData
+ */
+struct uiDropData {
+	/**
+	 * Number of entries in @p Paths.
+	 */
+	size_t NumPaths;
+
+	/**
+	 * Paths of dropped files and folders, in the OS file name encoding.
+	 */
+	const char * const *Paths;
+
+	/**
+	 * Dropped text, UTF-8. `NULL` if files were dropped.
+	 */
+	const char *Text;
+};
+
+/**
+ * Registers a callback for when files or text are dropped onto the control.
+ *
+ * Supported by uiWindow, uiMultilineEntry, uiArea and uiTable.
+ *
+ * @param c uiControl instance.
+ * @param f Callback function.\n
+ *          @p sender Back reference to the instance that triggered the callback.\n
+ *          @p data The dropped data, only valid during the callback.\n
+ *          @p senderData User data registered with the sender instance.\n
+ *          Return:\n
+ *          `TRUE` if the drop was accepted, `FALSE` otherwise.
+ * @param data User data to be passed to the callback.
+ *
+ * @note Only one callback can be registered at a time.
+ * @note While a callback is registered, the control's own handling of drops is disabled.
+ * @memberof uiControl
+ */
+_UI_EXTERN void uiControlOnDrop(uiControl *c,
+	int (*f)(uiControl *sender, const uiDropData *data, void *senderData), void *data);
+
+/**
+ * Registers a callback for when a drag with droppable data enters or leaves the control.
+ *
+ * @param c uiControl instance.
+ * @param f Callback function.\n
+ *          @p sender Back reference to the instance that triggered the callback.\n
+ *          @p entered `TRUE` if the drag entered the control, `FALSE` if it left.\n
+ *          @p senderData User data registered with the sender instance.
+ * @param data User data to be passed to the callback.
+ *
+ * @note Only one callback can be registered at a time.
+ * @note Only drags over controls with a callback registered via uiControlOnDrop() are reported.
+ * @memberof uiControl
+ */
+_UI_EXTERN void uiControlOnDragHover(uiControl *c,
+	void (*f)(uiControl *sender, int entered, void *senderData), void *data);
+
 
 /**
  * File chooser dialog window to select a single file.
@@ -2003,6 +3133,113 @@ _UI_EXTERN char *uiOpenFolder(uiWindow *parent);
  */
 _UI_EXTERN char *uiSaveFile(uiWindow *parent);
 
+/**
+ * File dialog modes.
+ *
+ * @enum uiFileDialogMode
+ * @ingroup dialogWindow
+ */
+_UI_ENUM(uiFileDialogMode) {
+	uiFileDialogModeOpenFile,   //!< Select existing files.
+	uiFileDialogModeOpenFolder, //!< Select existing folders.
+	uiFileDialogModeSaveFile,   //!< Select a file to save to. Overwriting has to be confirmed.
+};
+
+typedef struct uiFileDialogFilter uiFileDialogFilter;
+
+/**
+ * A named filename filter of a file dialog.
+ *
+ * @struct uiFileDialogFilter
+ * @ingroup dialogWindow
+ */
+struct uiFileDialogFilter {
+	/**
+	 * Filter name shown to the user, e.g. `Images`.
+	 */
+	const char *Name;
+
+	/**
+	 * Number of entries in @p Patterns.
+	 */
+	size_t NumPatterns;
+
+	/**
+	 * Glob patterns, e.g. `*.png`.
+	 */
+	const char * const *Patterns;
+};
+
+typedef struct uiFileDialogParams uiFileDialogParams;
+
+/**
+ * Options of a file dialog.
+ *
+ * All strings are valid, `NUL` terminated and may be `NULL` to use the default.
+ * Paths are encoded like the paths returned by the dialog.
+ *
+ * @struct uiFileDialogParams
+ * @ingroup dialogWindow
+ */
+struct uiFileDialogParams {
+	/**
+	 * Dialog window title text, UTF-8.
+	 */
+	const char *Title;
+
+	/**
+	 * Folder initially shown.
+	 */
+	const char *Directory;
+
+	/**
+	 * Suggested file name, selected file in the open modes.
+	 */
+	const char *FileName;
+
+	/**
+	 * Number of entries in @p Filters. `0` shows all files.
+	 */
+	size_t NumFilters;
+
+	/**
+	 * Filename filters, the first one is active initially.
+	 */
+	const uiFileDialogFilter *Filters;
+
+	/**
+	 * `TRUE` to allow selecting multiple files or folders, ignored when saving.
+	 */
+	int MultipleSelection;
+
+	/**
+	 * `TRUE` to show hidden files.
+	 */
+	int ShowHidden;
+};
+
+/**
+ * File chooser dialog window with options.
+ *
+ * @param parent Parent window.
+ * @param mode What to select.
+ * @param params Dialog options.
+ * @returns `NULL` terminated array of paths, `NULL` on cancel.\n
+ *          Paths are in the OS file name encoding, which is not necessarily UTF-8.\n
+ *          Caller is responsible for freeing the data with `uiFreeFileDialogPaths()`.
+ * @note File paths are separated by the underlying OS file path separator.
+ * @ingroup dataEntry dialogWindow
+ */
+_UI_EXTERN char **uiFileDialog(uiWindow *parent, uiFileDialogMode mode, const uiFileDialogParams *params);
+
+/**
+ * Frees the paths returned by uiFileDialog().
+ *
+ * @param paths `NULL` terminated array of paths.
+ * @ingroup dialogWindow
+ */
+_UI_EXTERN void uiFreeFileDialogPaths(char **paths);
+
 /**
  * Message box dialog window.
  *
@@ -2036,6 +3273,55 @@ _UI_EXTERN void uiMsgBox(uiWindow *parent, const char *title, const char *descri
  */
 _UI_EXTERN void uiMsgBoxError(uiWindow *parent, const char *title, const char *description);
 
+/**
+ * Message dialog icons.
+ *
+ * @enum uiMessageDialogIcon
+ * @ingroup dialogWindow
+ */
+_UI_ENUM(uiMessageDialogIcon) {
+	uiMessageDialogIconInfo,     //!< Informational message.
+	uiMessageDialogIconWarning,  //!< Warning message.
+	uiMessageDialogIconError,    //!< Error message.
+	uiMessageDialogIconQuestion, //!< Question to the user.
+};
+
+/**
+ * Message dialog window asking the user to choose a button.
+ *
+ * @param parent Parent window.
+ * @param icon Kind of the message.
+ * @param title Dialog window title text.\n
+ *              A valid, `NUL` terminated UTF-8 string.\n
+ *              Data is copied internally. Ownership is not transferred.
+ * @param description Dialog message text.\n
+ *                    A valid, `NUL` terminated UTF-8 string.\n
+ *                    Data is copied internally. Ownership is not transferred.
+ * @param buttons Button labels from left to right, `NUL` terminated UTF-8 strings.
+ * @param numButtons Number of entries in @p buttons. `0` shows a single `OK` button.
+ * @param defaultButton Index of the button activated by `Enter`, `-1` for none.
+ * @param cancelButton Index of the button reported when the dialog is closed or `Escape` is pressed, `-1` for none.
+ * @returns Index of the chosen button, `-1` if the dialog was closed without a cancel button.
+ * @ingroup dialogWindow
+ */
+_UI_EXTERN int uiMessageDialog(uiWindow *parent, uiMessageDialogIcon icon, const char *title, const char *description, const char * const *buttons, int numButtons, int defaultButton, int cancelButton);
+
+/**
+ * Question dialog window with the platform's `OK` and `Cancel` buttons.
+ *
+ * @param parent Parent window.
+ * @param title Dialog window title text.\n
+ *              A valid, `NUL` terminated UTF-8 string.\n
+ *              Data is copied internally. Ownership is not transferred.
+ * @param description Dialog message text.\n
+ *                    A valid, `NUL` terminated UTF-8 string.\n
+ *                    Data is copied internally. Ownership is not transferred.
+ * @returns `TRUE` if the user confirmed, `FALSE` if declined or closed the dialog.
+ * @note The button labels and order follow the platform conventions.
+ * @ingroup dialogWindow
+ */
+_UI_EXTERN int uiMsgBoxConfirm(uiWindow *parent, const char *title, const char *description);
+
 typedef struct uiArea uiArea;
 typedef struct uiAreaHandler uiAreaHandler;
 typedef struct uiAreaDrawParams uiAreaDrawParams;
@@ -2667,6 +3953,69 @@ struct uiFontDescriptor {
 _UI_EXTERN void uiLoadControlFont(uiFontDescriptor *f);
 _UI_EXTERN void uiFreeFontDescriptor(uiFontDescriptor *desc);
 
+/**
+ * Sets the font of the control's text.
+ *
+ * @param c uiControl instance.
+ * @param font The font, `NULL` to use the default font.\n
+ *             Data is copied internally. Ownership is not transferred.
+ * @note Meant for controls displaying text, like uiLabel, uiEntry and uiMultilineEntry.
+ * @memberof uiControl
+ */
+_UI_EXTERN void uiControlSetFont(uiControl *c, const uiFontDescriptor *font);
+
+/**
+ * Returns the font the control's text is drawn with.
+ *
+ * @param c uiControl instance.
+ * @param[out] font The font, either set with uiControlSetFont() or the default font.\n
+ *                  Caller is responsible for freeing the data with `uiFreeFontDescriptor()`.
+ * @memberof uiControl
+ */
+_UI_EXTERN void uiControlFont(uiControl *c, uiFontDescriptor *font);
+
+/**
+ * Sets the color of the control's text.
+ *
+ * @param c uiControl instance.
+ * @param r Red. Double in range of [0, 1.0].
+ * @param g Green. Double in range of [0, 1.0].
+ * @param b Blue. Double in range of [0, 1.0].
+ * @param a Alpha. Double in range of [0, 1.0].
+ * @memberof uiControl
+ */
+_UI_EXTERN void uiControlSetTextColor(uiControl *c, double r, double g, double b, double a);
+
+/**
+ * Restores the default color of the control's text.
+ *
+ * @param c uiControl instance.
+ * @memberof uiControl
+ */
+_UI_EXTERN void uiControlResetTextColor(uiControl *c);
+
+/**
+ * Sets the background color of the control.
+ *
+ * @param c uiControl instance.
+ * @param r Red. Double in range of [0, 1.0].
+ * @param g Green. Double in range of [0, 1.0].
+ * @param b Blue. Double in range of [0, 1.0].
+ * @param a Alpha. Double in range of [0, 1.0].
+ * @note Controls without a background of their own, like uiLabel, may not show the color
+ *       on all platforms.
+ * @memberof uiControl
+ */
+_UI_EXTERN void uiControlSetBackgroundColor(uiControl *c, double r, double g, double b, double a);
+
+/**
+ * Restores the default background color of the control.
+ *
+ * @param c uiControl instance.
+ * @memberof uiControl
+ */
+_UI_EXTERN void uiControlResetBackgroundColor(uiControl *c);
+
 // uiDrawTextLayout is a concrete representation of a
 // uiAttributedString that can be displayed in a uiDrawContext.
 // It includes information important for the drawing of a block of
@@ -3212,6 +4561,54 @@ _UI_EXTERN void uiFreeImage(uiImage *i);
  */
 _UI_EXTERN void uiImageAppend(uiImage *i, void *pixels, int pixelWidth, int pixelHeight, int byteStride);
 
+/**
+ * Places text on the system clipboard, replacing its content.
+ *
+ * @param text Text to copy.\n
+ *             A valid, `NUL` terminated UTF-8 string.\n
+ *             Data is copied internally. Ownership is not transferred.
+ */
+_UI_EXTERN void uiClipboardSetText(const char *text);
+
+/**
+ * Returns the text on the system clipboard.
+ *
+ * @returns The clipboard text, `NULL` if the clipboard holds no text.\n
+ *          A `NUL` terminated UTF-8 string.\n
+ *          Caller is responsible for freeing the data with `uiFreeText()`.
+ * @note This may run the event loop while waiting for the clipboard owner.
+ */
+_UI_EXTERN char *uiClipboardText(void);
+
+/**
+ * Places an image on the system clipboard, replacing its content.
+ *
+ * @param image uiImage instance. The largest representation is copied.\n
+ *              Data is copied internally. Ownership is not transferred.
+ */
+_UI_EXTERN void uiClipboardSetImage(uiImage *image);
+
+/**
+ * Returns the image on the system clipboard.
+ *
+ * @returns A new uiImage instance with a single representation, `NULL` if the
+ *          clipboard holds no image.\n
+ *          Caller is responsible for freeing the data with `uiFreeImage()`.
+ * @note This may run the event loop while waiting for the clipboard owner.
+ */
+_UI_EXTERN uiImage *uiClipboardImage(void);
+
+/**
+ * Registers a callback for when the content of the system clipboard changes.
+ *
+ * @param f Callback function.\n
+ *          @p senderData User data registered with the sender instance.
+ * @param data User data to be passed to the callback.
+ *
+ * @note Only one callback can be registered at a time.
+ */
+_UI_EXTERN void uiClipboardOnChanged(void (*f)(void *senderData), void *data);
+
 /**
  * @addtogroup table
  * @{
@@ -382,6 +382,37 @@ static const struct {
 	{ GDK_KEY_Print, 0 },
 };
 
+guint uiprivExtKeyToKeyval(uiExtKey extKey)
+{
+	int i;
+
+	// numpad numeric keys and . are not in extKeys; see above
+	if (extKey >= uiExtKeyN0 && extKey <= uiExtKeyN9)
+		return GDK_KEY_KP_0 + (extKey - uiExtKeyN0);
+	if (extKey == uiExtKeyNDot)
+		return GDK_KEY_KP_Decimal;
+	for (i = 0; extKeys[i].keyval != GDK_KEY_Print; i++)
+		if (extKeys[i].extkey == extKey)
+			return extKeys[i].keyval;
+	return 0;
+}
+
+GdkModifierType uiprivToGdkModifiers(uiModifiers m)
+{
+	GdkModifierType state;
+
+	state = 0;
+	if ((m & uiModifierCtrl) != 0)
+		state |= GDK_CONTROL_MASK;
+	if ((m & uiModifierAlt) != 0)
+		state |= GDK_MOD1_MASK;
+	if ((m & uiModifierShift) != 0)
+		state |= GDK_SHIFT_MASK;
+	if ((m & uiModifierSuper) != 0)
+		state |= GDK_SUPER_MASK;
+	return state;
+}
+
 static int areaKeyEvent(uiArea *a, int up, GdkEventKey *e)
 {
 	uiAreaKeyEvent ke;
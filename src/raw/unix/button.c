@@ -39,6 +39,11 @@ void uiButtonOnClicked(uiButton *b, void (*f)(uiButton *, void *), void *data)
 	b->onClickedData = data;
 }
 
+void uiButtonClick(uiButton *b)
+{
+	gtk_button_clicked(b->button);
+}
+
 uiButton *uiNewButton(const char *text)
 {
 	uiButton *b;
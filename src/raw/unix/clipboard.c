@@ -0,0 +1,95 @@
+// 19 october 2026
+#include "uipriv_unix.h"
+
+static void (*onChanged)(void *) = NULL;
+static void *onChangedData = NULL;
+static gboolean ownerChangeConnected = FALSE;
+
+static GtkClipboard *clipboard(void)
+{
+	return gtk_clipboard_get(GDK_SELECTION_CLIPBOARD);
+}
+
+void uiClipboardSetText(const char *text)
+{
+	gtk_clipboard_set_text(clipboard(), text, -1);
+	gtk_clipboard_store(clipboard());
+}
+
+char *uiClipboardText(void)
+{
+	// already allocated with g_malloc(), which is what uiFreeText() frees with
+	return gtk_clipboard_wait_for_text(clipboard());
+}
+
+void uiClipboardSetImage(uiImage *image)
+{
+	GList *pixbufs, *l;
+	GdkPixbuf *best = NULL;
+
+	pixbufs = uiprivImagePixbufs(image);
+	for (l = pixbufs; l != NULL; l = l->next)
+		if (best == NULL || gdk_pixbuf_get_width(GDK_PIXBUF(l->data)) > gdk_pixbuf_get_width(best))
+			best = GDK_PIXBUF(l->data);
+	if (best != NULL) {
+		// the clipboard takes its own reference
+		gtk_clipboard_set_image(clipboard(), best);
+		gtk_clipboard_store(clipboard());
+	}
+	g_list_free_full(pixbufs, g_object_unref);
+}
+
+uiImage *uiClipboardImage(void)
+{
+	GdkPixbuf *pixbuf;
+	uiImage *image;
+	const guchar *src;
+	guchar *pixels, *dst;
+	int width, height, stride, channels;
+	int x, y;
+
+	pixbuf = gtk_clipboard_wait_for_image(clipboard());
+	if (pixbuf == NULL)
+		return NULL;
+	width = gdk_pixbuf_get_width(pixbuf);
+	height = gdk_pixbuf_get_height(pixbuf);
+	stride = gdk_pixbuf_get_rowstride(pixbuf);
+	channels = gdk_pixbuf_get_n_channels(pixbuf);
+	src = gdk_pixbuf_get_pixels(pixbuf);
+
+	// GdkPixbuf is not premultiplied, uiImage is
+	pixels = (guchar *) g_malloc(width * height * 4);
+	for (y = 0; y < height; y++) {
+		dst = pixels + y * width * 4;
+		for (x = 0; x < width; x++) {
+			const guchar *p = src + y * stride + x * channels;
+			guchar a = (channels == 4) ? p[3] : 255;
+
+			dst[x * 4] = (p[0] * a + 127) / 255;
+			dst[x * 4 + 1] = (p[1] * a + 127) / 255;
+			dst[x * 4 + 2] = (p[2] * a + 127) / 255;
+			dst[x * 4 + 3] = a;
+		}
+	}
+	image = uiNewImage(width, height);
+	uiImageAppend(image, pixels, width, height, width * 4);
+	g_free(pixels);
+	g_object_unref(pixbuf);
+	return image;
+}
+
+static void onOwnerChange(GtkClipboard *cb, GdkEvent *e, gpointer data)
+{
+	if (onChanged != NULL)
+		(*onChanged)(onChangedData);
+}
+
+void uiClipboardOnChanged(void (*f)(void *), void *data)
+{
+	onChanged = f;
+	onChangedData = data;
+	if (!ownerChangeConnected) {
+		g_signal_connect(clipboard(), "owner-change", G_CALLBACK(onOwnerChange), NULL);
+		ownerChangeConnected = TRUE;
+	}
+}
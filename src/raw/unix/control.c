@@ -3,6 +3,8 @@
 
 void uiUnixControlSetContainer(uiUnixControl *c, GtkContainer *container, gboolean remove)
 {
+	if (!remove)
+		uiprivSetWidgetControl(uiControl(c));
 	(*(c->SetContainer))(c, container, remove);
 }
 
@@ -12,3 +14,73 @@ uiUnixControl *uiUnixAllocControl(size_t n, uint32_t typesig, const char *typena
 {
 	return uiUnixControl(uiAllocControl(n, uiUnixControlSignature, typesig, typenamestr));
 }
+
+struct destroyedHandler {
+	uiControl *c;
+	void (*f)(uiControl *, void *);
+	void *data;
+};
+
+static void onDestroyed(GtkWidget *widget, gpointer data)
+{
+	struct destroyedHandler *h = (struct destroyedHandler *) data;
+
+	(*(h->f))(h->c, h->data);
+}
+
+static void freeDestroyedHandler(gpointer data, GClosure *closure)
+{
+	uiprivFree(data);
+}
+
+void uiControlOnDestroyed(uiControl *c, void (*f)(uiControl *, void *), void *data)
+{
+	struct destroyedHandler *h;
+
+	h = uiprivNew(struct destroyedHandler);
+	h->c = c;
+	h->f = f;
+	h->data = data;
+	g_signal_connect_data(GTK_WIDGET(uiControlHandle(c)), "destroy",
+		G_CALLBACK(onDestroyed), h, freeDestroyedHandler, 0);
+}
+
+void uiControlSetTooltip(uiControl *c, const char *tooltip)
+{
+	gtk_widget_set_tooltip_text(GTK_WIDGET(uiControlHandle(c)), tooltip);
+}
+
+// lets a widget found by walking up from the focus widget be mapped back to its control
+void uiprivSetWidgetControl(uiControl *c)
+{
+	g_object_set_data(G_OBJECT(uiControlHandle(c)), "libui-control", c);
+}
+
+uiControl *uiprivWidgetControl(GtkWidget *widget)
+{
+	uiControl *c;
+
+	for (; widget != NULL; widget = gtk_widget_get_parent(widget)) {
+		c = (uiControl *) g_object_get_data(G_OBJECT(widget), "libui-control");
+		if (c != NULL)
+			return c;
+	}
+	return NULL;
+}
+
+// scrolled controls like uiMultilineEntry and uiTable do their work in their scrolled child
+GtkWidget *uiprivControlWidget(uiControl *c)
+{
+	GtkWidget *widget, *child;
+
+	widget = GTK_WIDGET(uiControlHandle(c));
+	if (GTK_IS_SCROLLED_WINDOW(widget)) {
+		child = gtk_bin_get_child(GTK_BIN(widget));
+		// widgets without native scrolling are wrapped in a GtkViewport
+		if (GTK_IS_VIEWPORT(child))
+			child = gtk_bin_get_child(GTK_BIN(child));
+		if (child != NULL)
+			widget = child;
+	}
+	return widget;
+}
@@ -0,0 +1,143 @@
+// 19 october 2026
+#include "uipriv_unix.h"
+
+struct dropTarget {
+	uiControl *c;
+	int (*onDrop)(uiControl *, const uiDropData *, void *);
+	void *onDropData;
+	void (*onDragHover)(uiControl *, int, void *);
+	void *onDragHoverData;
+	gboolean hovering;
+};
+
+static void setHovering(struct dropTarget *t, gboolean hovering)
+{
+	if (t->hovering == hovering)
+		return;
+	t->hovering = hovering;
+	if (t->onDragHover != NULL)
+		(*(t->onDragHover))(t->c, hovering, t->onDragHoverData);
+}
+
+static gboolean onDragMotion(GtkWidget *widget, GdkDragContext *context, gint x, gint y, guint time, gpointer data)
+{
+	struct dropTarget *t = (struct dropTarget *) data;
+
+	if (t->onDrop == NULL)
+		return FALSE;
+	if (gtk_drag_dest_find_target(widget, context, NULL) == GDK_NONE) {
+		gdk_drag_status(context, 0, time);
+		return TRUE;
+	}
+	setHovering(t, TRUE);
+	gdk_drag_status(context, GDK_ACTION_COPY, time);
+	// returning TRUE also keeps widgets like GtkTextView from showing their own drop position
+	return TRUE;
+}
+
+static void onDragLeave(GtkWidget *widget, GdkDragContext *context, guint time, gpointer data)
+{
+	struct dropTarget *t = (struct dropTarget *) data;
+
+	setHovering(t, FALSE);
+}
+
+static gboolean onDragDrop(GtkWidget *widget, GdkDragContext *context, gint x, gint y, guint time, gpointer data)
+{
+	struct dropTarget *t = (struct dropTarget *) data;
+	GdkAtom target;
+
+	if (t->onDrop == NULL)
+		return FALSE;
+	target = gtk_drag_dest_find_target(widget, context, NULL);
+	if (target == GDK_NONE)
+		return FALSE;
+	gtk_drag_get_data(widget, context, target, time);
+	return TRUE;
+}
+
+static void onDragDataReceived(GtkWidget *widget, GdkDragContext *context, gint x, gint y, GtkSelectionData *selection, guint info, guint time, gpointer data)
+{
+	struct dropTarget *t = (struct dropTarget *) data;
+	uiDropData d;
+	gchar **uris;
+	gchar *text = NULL;
+	GPtrArray *paths;
+	char *path;
+	int ok = 0;
+	guint i;
+
+	if (t->onDrop == NULL)
+		return;
+	// otherwise GtkTextView would insert the dropped data itself
+	g_signal_stop_emission_by_name(widget, "drag-data-received");
+
+	paths = g_ptr_array_new_with_free_func(g_free);
+	uris = gtk_selection_data_get_uris(selection);
+	if (uris != NULL) {
+		for (i = 0; uris[i] != NULL; i++) {
+			// only local files have a path
+			path = g_filename_from_uri(uris[i], NULL, NULL);
+			if (path != NULL)
+				g_ptr_array_add(paths, path);
+		}
+		g_strfreev(uris);
+	} else
+		text = (gchar *) gtk_selection_data_get_text(selection);
+
+	d.NumPaths = paths->len;
+	d.Paths = (const char * const *) (paths->pdata);
+	d.Text = text;
+	if (paths->len != 0 || text != NULL)
+		ok = (*(t->onDrop))(t->c, &d, t->onDropData);
+	g_ptr_array_free(paths, TRUE);
+	g_free(text);
+	gtk_drag_finish(context, ok != 0, FALSE, time);
+}
+
+static struct dropTarget *dropTarget(uiControl *c)
+{
+	GtkWidget *widget;
+	GtkTargetList *targets;
+	struct dropTarget *t;
+
+	widget = uiprivControlWidget(c);
+	t = (struct dropTarget *) g_object_get_data(G_OBJECT(widget), "libui-drop-target");
+	if (t != NULL)
+		return t;
+
+	t = uiprivNew(struct dropTarget);
+	t->c = c;
+	g_object_set_data_full(G_OBJECT(widget), "libui-drop-target", t, uiprivFree);
+
+	gtk_drag_dest_set(widget, 0, NULL, 0, GDK_ACTION_COPY);
+	targets = gtk_target_list_new(NULL, 0);
+	gtk_target_list_add_uri_targets(targets, 0);
+	gtk_target_list_add_text_targets(targets, 0);
+	gtk_drag_dest_set_target_list(widget, targets);
+	gtk_target_list_unref(targets);
+
+	g_signal_connect(widget, "drag-motion", G_CALLBACK(onDragMotion), t);
+	g_signal_connect(widget, "drag-leave", G_CALLBACK(onDragLeave), t);
+	g_signal_connect(widget, "drag-drop", G_CALLBACK(onDragDrop), t);
+	g_signal_connect(widget, "drag-data-received", G_CALLBACK(onDragDataReceived), t);
+	return t;
+}
+
+void uiControlOnDrop(uiControl *c, int (*f)(uiControl *, const uiDropData *, void *), void *data)
+{
+	struct dropTarget *t;
+
+	t = dropTarget(c);
+	t->onDrop = f;
+	t->onDropData = data;
+}
+
+void uiControlOnDragHover(uiControl *c, void (*f)(uiControl *, int, void *), void *data)
+{
+	struct dropTarget *t;
+
+	t = dropTarget(c);
+	t->onDragHover = f;
+	t->onDragHoverData = data;
+}
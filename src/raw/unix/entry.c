@@ -9,6 +9,8 @@ struct uiEntry {
 	void (*onChanged)(uiEntry *, void *);
 	void *onChangedData;
 	gulong onChangedSignal;
+	void (*onActivate)(uiEntry *, void *);
+	void *onActivateData;
 };
 
 uiUnixControlAllDefaults(uiEntry)
@@ -25,6 +27,18 @@ static void defaultOnChanged(uiEntry *e, void *data)
 	// do nothing
 }
 
+static void onActivate(GtkEntry *entry, gpointer data)
+{
+	uiEntry *e = uiEntry(data);
+
+	(*(e->onActivate))(e, e->onActivateData);
+}
+
+static void defaultOnActivate(uiEntry *e, void *data)
+{
+	// do nothing
+}
+
 char *uiEntryText(uiEntry *e)
 {
 	return uiUnixStrdupText(gtk_entry_get_text(e->entry));
@@ -60,6 +74,58 @@ void uiEntrySetReadOnly(uiEntry *e, int readonly)
 	gtk_editable_set_editable(e->editable, editable);
 }
 
+void uiEntryOnActivate(uiEntry *e, void (*f)(uiEntry *, void *), void *data)
+{
+	e->onActivate = f;
+	e->onActivateData = data;
+}
+
+void uiEntrySetPlaceholder(uiEntry *e, const char *text)
+{
+	gtk_entry_set_placeholder_text(e->entry, text);
+}
+
+int uiEntryMaxLength(uiEntry *e)
+{
+	return gtk_entry_get_max_length(e->entry);
+}
+
+void uiEntrySetMaxLength(uiEntry *e, int length)
+{
+	gtk_entry_set_max_length(e->entry, length);
+}
+
+int uiEntryVisibility(uiEntry *e)
+{
+	return gtk_entry_get_visibility(e->entry) != FALSE;
+}
+
+void uiEntrySetVisibility(uiEntry *e, int visible)
+{
+	gtk_entry_set_visibility(e->entry, visible != 0);
+}
+
+void uiEntrySelectRange(uiEntry *e, int start, int end)
+{
+	gtk_editable_select_region(e->editable, start, end);
+}
+
+int uiEntrySelection(uiEntry *e, int *start, int *end)
+{
+	gint s, t;
+	gboolean selected;
+
+	selected = gtk_editable_get_selection_bounds(e->editable, &s, &t);
+	*start = s;
+	*end = t;
+	return selected != FALSE;
+}
+
+int uiEntryCursorPosition(uiEntry *e)
+{
+	return gtk_editable_get_position(e->editable);
+}
+
 static uiEntry *finishNewEntry(GtkWidget *w, const gchar *signal)
 {
 	uiEntry *e;
@@ -73,6 +139,9 @@ static uiEntry *finishNewEntry(GtkWidget *w, const gchar *signal)
 	e->onChangedSignal = g_signal_connect(e->widget, signal, G_CALLBACK(onChanged), e);
 	uiEntryOnChanged(e, defaultOnChanged, NULL);
 
+	g_signal_connect(e->widget, "activate", G_CALLBACK(onActivate), e);
+	uiEntryOnActivate(e, defaultOnActivate, NULL);
+
 	return e;
 }
 
@@ -0,0 +1,119 @@
+// 19 october 2026
+#include "uipriv_unix.h"
+
+struct focusTarget {
+	uiControl *c;
+	void (*onFocusIn)(uiControl *, void *);
+	void *onFocusInData;
+	void (*onFocusOut)(uiControl *, void *);
+	void *onFocusOutData;
+	gboolean focused;
+};
+
+static void setFocused(struct focusTarget *t, gboolean focused)
+{
+	if (t->focused == focused)
+		return;
+	t->focused = focused;
+	if (focused) {
+		if (t->onFocusIn != NULL)
+			(*(t->onFocusIn))(t->c, t->onFocusInData);
+	} else {
+		if (t->onFocusOut != NULL)
+			(*(t->onFocusOut))(t->c, t->onFocusOutData);
+	}
+}
+
+static gboolean onFocusInEvent(GtkWidget *widget, GdkEventFocus *e, gpointer data)
+{
+	setFocused((struct focusTarget *) data, TRUE);
+	return FALSE;
+}
+
+static gboolean onFocusOutEvent(GtkWidget *widget, GdkEventFocus *e, gpointer data)
+{
+	setFocused((struct focusTarget *) data, FALSE);
+	return FALSE;
+}
+
+// controls made of several widgets, like uiEditableCombobox or uiRadioButtons, are focused through their children
+static void onSetFocusChild(GtkContainer *container, GtkWidget *child, gpointer data)
+{
+	setFocused((struct focusTarget *) data, child != NULL);
+}
+
+static struct focusTarget *focusTarget(uiControl *c)
+{
+	GtkWidget *widget;
+	struct focusTarget *t;
+
+	widget = uiprivControlWidget(c);
+	t = (struct focusTarget *) g_object_get_data(G_OBJECT(widget), "libui-focus-target");
+	if (t != NULL)
+		return t;
+
+	t = uiprivNew(struct focusTarget);
+	t->c = c;
+	t->focused = uiControlHasFocus(c);
+	g_object_set_data_full(G_OBJECT(widget), "libui-focus-target", t, uiprivFree);
+
+	g_signal_connect(widget, "focus-in-event", G_CALLBACK(onFocusInEvent), t);
+	g_signal_connect(widget, "focus-out-event", G_CALLBACK(onFocusOutEvent), t);
+	if (GTK_IS_CONTAINER(widget))
+		g_signal_connect(widget, "set-focus-child", G_CALLBACK(onSetFocusChild), t);
+	return t;
+}
+
+void uiControlFocus(uiControl *c)
+{
+	GtkWidget *widget;
+
+	widget = uiprivControlWidget(c);
+	if (gtk_widget_get_can_focus(widget))
+		gtk_widget_grab_focus(widget);
+	else
+		// containers focus their first focusable child instead
+		gtk_widget_child_focus(widget, GTK_DIR_TAB_FORWARD);
+}
+
+int uiControlHasFocus(uiControl *c)
+{
+	GtkWidget *widget, *toplevel, *focus;
+
+	widget = uiprivControlWidget(c);
+	toplevel = gtk_widget_get_toplevel(widget);
+	if (!GTK_IS_WINDOW(toplevel))
+		return 0;
+	focus = gtk_window_get_focus(GTK_WINDOW(toplevel));
+	if (focus == NULL)
+		return 0;
+	return focus == widget || gtk_widget_is_ancestor(focus, widget);
+}
+
+void uiControlOnFocusIn(uiControl *c, void (*f)(uiControl *, void *), void *data)
+{
+	struct focusTarget *t;
+
+	t = focusTarget(c);
+	t->onFocusIn = f;
+	t->onFocusInData = data;
+}
+
+void uiControlOnFocusOut(uiControl *c, void (*f)(uiControl *, void *), void *data)
+{
+	struct focusTarget *t;
+
+	t = focusTarget(c);
+	t->onFocusOut = f;
+	t->onFocusOutData = data;
+}
+
+uiControl *uiWindowFocusedControl(uiWindow *w)
+{
+	GtkWidget *focus;
+
+	focus = gtk_window_get_focus(GTK_WINDOW(uiControlHandle(uiControl(w))));
+	if (focus == NULL)
+		return NULL;
+	return uiprivWidgetControl(focus);
+}
@@ -69,6 +69,7 @@ static GtkWidget *prepare(struct gridChild *gc, uiControl *c, int hexpand, uiAli
 
 	gc->c = c;
 	widget = GTK_WIDGET(uiControlHandle(gc->c));
+	uiprivSetWidgetControl(gc->c);
 	gc->oldhexpand = gtk_widget_get_hexpand(widget);
 	gc->oldhalign = gtk_widget_get_halign(widget);
 	gc->oldvexpand = gtk_widget_get_vexpand(widget);
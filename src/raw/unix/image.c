@@ -133,3 +133,22 @@ cairo_surface_t *uiprivImageAppropriateSurface(uiImage *i, GtkWidget *w)
 	g_ptr_array_foreach(i->images, match, &m);
 	return m.best;
 }
+
+static void appendPixbuf(gpointer surface, gpointer data)
+{
+	cairo_surface_t *cs = (cairo_surface_t *) surface;
+	GList **list = (GList **) data;
+
+	*list = g_list_append(*list, gdk_pixbuf_get_from_surface(cs, 0, 0,
+		cairo_image_surface_get_width(cs),
+		cairo_image_surface_get_height(cs)));
+}
+
+// the caller owns the list and the pixbufs; free with g_list_free_full(list, g_object_unref)
+GList *uiprivImagePixbufs(uiImage *i)
+{
+	GList *list = NULL;
+
+	g_ptr_array_foreach(i->images, appendPixbuf, &list);
+	return list;
+}
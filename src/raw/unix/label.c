@@ -20,6 +20,116 @@ void uiLabelSetText(uiLabel *l, const char *text)
 	gtk_label_set_text(l->label, text);
 }
 
+int uiLabelSetMarkup(uiLabel *l, const char *markup)
+{
+	// GtkLabel clears itself on invalid markup; leave it alone instead
+	if (!pango_parse_markup(markup, -1, 0, NULL, NULL, NULL, NULL))
+		return 0;
+	gtk_label_set_markup(l->label, markup);
+	return 1;
+}
+
+int uiLabelTextAlign(uiLabel *l)
+{
+	switch (gtk_label_get_justify(l->label)) {
+	case GTK_JUSTIFY_CENTER:
+		return uiDrawTextAlignCenter;
+	case GTK_JUSTIFY_RIGHT:
+		return uiDrawTextAlignRight;
+	default:
+		return uiDrawTextAlignLeft;
+	}
+}
+
+void uiLabelSetTextAlign(uiLabel *l, int align)
+{
+	gfloat xalign;
+	GtkJustification justify;
+
+	switch (align) {
+	case uiDrawTextAlignCenter:
+		xalign = 0.5;
+		justify = GTK_JUSTIFY_CENTER;
+		break;
+	case uiDrawTextAlignRight:
+		xalign = 1;
+		justify = GTK_JUSTIFY_RIGHT;
+		break;
+	default:
+		xalign = 0;
+		justify = GTK_JUSTIFY_LEFT;
+	}
+	// the alignment places the text as a whole, the justification the lines within it
+	gtk_misc_set_alignment(l->misc, xalign, 0);
+	gtk_label_set_justify(l->label, justify);
+}
+
+int uiLabelWrap(uiLabel *l)
+{
+	return gtk_label_get_line_wrap(l->label) != FALSE;
+}
+
+void uiLabelSetWrap(uiLabel *l, int wrap)
+{
+	gtk_label_set_line_wrap(l->label, wrap);
+	// break overlong words rather than overflowing
+	gtk_label_set_line_wrap_mode(l->label, PANGO_WRAP_WORD_CHAR);
+}
+
+int uiLabelMaxWidth(uiLabel *l)
+{
+	return gtk_label_get_max_width_chars(l->label);
+}
+
+void uiLabelSetMaxWidth(uiLabel *l, int chars)
+{
+	gtk_label_set_max_width_chars(l->label, chars);
+}
+
+uiLabelEllipsize uiLabelEllipsizeMode(uiLabel *l)
+{
+	switch (gtk_label_get_ellipsize(l->label)) {
+	case PANGO_ELLIPSIZE_START:
+		return uiLabelEllipsizeStart;
+	case PANGO_ELLIPSIZE_MIDDLE:
+		return uiLabelEllipsizeMiddle;
+	case PANGO_ELLIPSIZE_END:
+		return uiLabelEllipsizeEnd;
+	default:
+		return uiLabelEllipsizeNone;
+	}
+}
+
+void uiLabelSetEllipsizeMode(uiLabel *l, uiLabelEllipsize mode)
+{
+	PangoEllipsizeMode m;
+
+	switch (mode) {
+	case uiLabelEllipsizeStart:
+		m = PANGO_ELLIPSIZE_START;
+		break;
+	case uiLabelEllipsizeMiddle:
+		m = PANGO_ELLIPSIZE_MIDDLE;
+		break;
+	case uiLabelEllipsizeEnd:
+		m = PANGO_ELLIPSIZE_END;
+		break;
+	default:
+		m = PANGO_ELLIPSIZE_NONE;
+	}
+	gtk_label_set_ellipsize(l->label, m);
+}
+
+int uiLabelSelectable(uiLabel *l)
+{
+	return gtk_label_get_selectable(l->label) != FALSE;
+}
+
+void uiLabelSetSelectable(uiLabel *l, int selectable)
+{
+	gtk_label_set_selectable(l->label, selectable);
+}
+
 uiLabel *uiNewLabel(const char *text)
 {
 	uiLabel *l;
@@ -2,6 +2,7 @@
 #include "uipriv_unix.h"
 
 static GArray *menus = NULL;
+static GArray *popups = NULL;
 static gboolean menusFinalized = FALSE;
 static gboolean hasQuit = FALSE;
 static gboolean hasPreferences = FALSE;
@@ -10,6 +11,8 @@ static gboolean hasAbout = FALSE;
 struct uiMenu {
 	char *name;
 	GArray *items;					// []*uiMenuItem
+	uiMenuItem *item;				// the item hosting a submenu; NULL for top-level menus
+	gboolean popup;
 };
 
 struct uiMenuItem {
@@ -20,12 +23,17 @@ struct uiMenuItem {
 	GType gtype;					// template for new instances; kept in sync with everything else
 	gboolean disabled;
 	gboolean checked;
+	guint accelKey;
+	GdkModifierType accelMods;
+	uiMenu *parent;
+	uiMenu *submenu;				// only for typeSubmenu
 	GHashTable *windows;			// map[GtkMenuItem]*menuItemWindow
 };
 
 struct menuItemWindow {
 	uiWindow *w;
 	gulong signal;
+	GtkAccelGroup *accel;
 };
 
 enum {
@@ -35,6 +43,7 @@ enum {
 	typePreferences,
 	typeAbout,
 	typeSeparator,
+	typeSubmenu,
 };
 
 // we do NOT want programmatic updates to raise an ::activated signal
@@ -130,17 +139,148 @@ void uiMenuItemSetChecked(uiMenuItem *item, int checked)
 	setChecked(item, c);
 }
 
+char *uiMenuItemText(uiMenuItem *item)
+{
+	return uiUnixStrdupText(item->name);
+}
+
+void uiMenuItemSetText(uiMenuItem *item, const char *text)
+{
+	GHashTableIter iter;
+	gpointer widget;
+
+	if (item->type == typeSeparator)
+		uiprivUserBug("You cannot set the text of a separator menu item.");
+	g_free(item->name);
+	item->name = g_strdup(text);
+	g_hash_table_iter_init(&iter, item->windows);
+	while (g_hash_table_iter_next(&iter, &widget, NULL))
+		gtk_menu_item_set_label(GTK_MENU_ITEM(widget), item->name);
+}
+
+static void setAccelerator(GtkWidget *menuitem, uiMenuItem *item, struct menuItemWindow *w, guint oldKey, GdkModifierType oldMods)
+{
+	if (w->accel == NULL)
+		return;
+	if (oldKey != 0)
+		gtk_widget_remove_accelerator(menuitem, w->accel, oldKey, oldMods);
+	if (item->accelKey != 0)
+		gtk_widget_add_accelerator(menuitem, "activate", w->accel, item->accelKey, item->accelMods, GTK_ACCEL_VISIBLE);
+}
+
+void uiMenuItemSetAccelerator(uiMenuItem *item, int modifiers, uint32_t key, int extKey)
+{
+	GHashTableIter iter;
+	gpointer widget;
+	gpointer ww;
+	guint oldKey;
+	GdkModifierType oldMods;
+
+	if (item->type == typeSeparator || item->type == typeSubmenu)
+		uiprivUserBug("You cannot set an accelerator on a separator or submenu menu item.");
+	oldKey = item->accelKey;
+	oldMods = item->accelMods;
+	item->accelKey = 0;
+	item->accelMods = 0;
+	if (extKey != 0)
+		item->accelKey = uiprivExtKeyToKeyval(extKey);
+	else if (key != 0)
+		item->accelKey = gdk_keyval_to_lower(gdk_unicode_to_keyval(key));
+	if (item->accelKey != 0)
+		item->accelMods = uiprivToGdkModifiers(modifiers);
+	g_hash_table_iter_init(&iter, item->windows);
+	while (g_hash_table_iter_next(&iter, &widget, &ww))
+		setAccelerator(GTK_WIDGET(widget), item, (struct menuItemWindow *) ww, oldKey, oldMods);
+}
+
+static void freeItem(uiMenuItem *item);
+
+static void freeItems(GArray *items)
+{
+	guint i;
+
+	for (i = 0; i < items->len; i++)
+		freeItem(g_array_index(items, uiMenuItem *, i));
+	g_array_free(items, TRUE);
+}
+
+static void freeItem(uiMenuItem *item)
+{
+	if (g_hash_table_size(item->windows) != 0)
+		// TODO is this really a uiprivUserBug()?
+		uiprivImplBug("menu item %p (%s) still has uiWindows attached; did you forget to destroy some windows?", item, item->name);
+	if (item->submenu != NULL) {
+		g_free(item->submenu->name);
+		freeItems(item->submenu->items);
+		uiprivFree(item->submenu);
+	}
+	g_free(item->name);
+	g_hash_table_destroy(item->windows);
+	uiprivFree(item);
+}
+
+static void freeMenuItemWindow(gpointer widget, gpointer ww, gpointer data)
+{
+	uiprivFree(ww);
+}
+
+// the widgets of a submenu go away together with the menu item hosting it; only forget about them here
+static void forgetWidgets(uiMenuItem *item)
+{
+	guint i;
+
+	g_hash_table_foreach(item->windows, freeMenuItemWindow, NULL);
+	g_hash_table_remove_all(item->windows);
+	if (item->submenu != NULL)
+		for (i = 0; i < item->submenu->items->len; i++)
+			forgetWidgets(g_array_index(item->submenu->items, uiMenuItem *, i));
+}
+
+void uiMenuItemDelete(uiMenuItem *item)
+{
+	GHashTableIter iter;
+	gpointer widget;
+	GArray *items;
+	guint i;
+
+	g_hash_table_iter_init(&iter, item->windows);
+	while (g_hash_table_iter_next(&iter, &widget, NULL))
+		gtk_widget_destroy(GTK_WIDGET(widget));
+	forgetWidgets(item);
+
+	items = item->parent->items;
+	for (i = 0; i < items->len; i++)
+		if (g_array_index(items, uiMenuItem *, i) == item) {
+			g_array_remove_index(items, i);
+			break;
+		}
+
+	switch (item->type) {
+	case typeQuit:
+		hasQuit = FALSE;
+		break;
+	case typePreferences:
+		hasPreferences = FALSE;
+		break;
+	case typeAbout:
+		hasAbout = FALSE;
+		break;
+	}
+	freeItem(item);
+}
+
 static uiMenuItem *newItem(uiMenu *m, int type, const char *name)
 {
 	uiMenuItem *item;
 
-	if (menusFinalized)
+	if (menusFinalized && !m->popup)
 		uiprivUserBug("You cannot create a new menu item after menus have been finalized.");
 
 	item = uiprivNew(uiMenuItem);
 
 	g_array_append_val(m->items, item);
 
+	item->parent = m;
 	item->type = type;
 	switch (item->type) {
 	case typeQuit:
@@ -173,6 +313,9 @@ static uiMenuItem *newItem(uiMenu *m, int type, const char *name)
 	case typeSeparator:
 		item->gtype = GTK_TYPE_SEPARATOR_MENU_ITEM;
 		break;
+	case typeSubmenu:
+		item->gtype = GTK_TYPE_MENU_ITEM;
+		break;
 	default:
 		item->gtype = GTK_TYPE_MENU_ITEM;
 		break;
@@ -195,6 +338,8 @@ uiMenuItem *uiMenuAppendCheckItem(uiMenu *m, const char *name)
 
 uiMenuItem *uiMenuAppendQuitItem(uiMenu *m)
 {
+	if (m->popup)
+		uiprivUserBug("You cannot add a Quit menu item to a popup menu.");
 	if (hasQuit)
 		uiprivUserBug("You cannot have multiple Quit menu items in the same program.");
 	hasQuit = TRUE;
@@ -204,6 +349,8 @@ uiMenuItem *uiMenuAppendQuitItem(uiMenu *m)
 
 uiMenuItem *uiMenuAppendPreferencesItem(uiMenu *m)
 {
+	if (m->popup)
+		uiprivUserBug("You cannot add a Preferences menu item to a popup menu.");
 	if (hasPreferences)
 		uiprivUserBug("You cannot have multiple Preferences menu items in the same program.");
 	hasPreferences = TRUE;
@@ -213,6 +360,8 @@ uiMenuItem *uiMenuAppendPreferencesItem(uiMenu *m)
 
 uiMenuItem *uiMenuAppendAboutItem(uiMenu *m)
 {
+	if (m->popup)
+		uiprivUserBug("You cannot add an About menu item to a popup menu.");
 	if (hasAbout)
 		uiprivUserBug("You cannot have multiple About menu items in the same program.");
 	hasAbout = TRUE;
@@ -225,6 +374,34 @@ void uiMenuAppendSeparator(uiMenu *m)
 	newItem(m, typeSeparator, NULL);
 }
 
+static uiMenu *newMenu(const char *name)
+{
+	uiMenu *m;
+
+	m = uiprivNew(uiMenu);
+	m->name = g_strdup(name);
+	m->items = g_array_new(FALSE, TRUE, sizeof (uiMenuItem *));
+	return m;
+}
+
+uiMenu *uiMenuAppendSubmenu(uiMenu *m, const char *name)
+{
+	uiMenuItem *item;
+
+	item = newItem(m, typeSubmenu, name);
+	item->submenu = newMenu(name);
+	item->submenu->item = item;
+	item->submenu->popup = m->popup;
+	return item->submenu;
+}
+
+void uiMenuDelete(uiMenu *m)
+{
+	if (m->item == NULL)
+		uiprivUserBug("You cannot delete a top-level menu; only submenus can be deleted.");
+	uiMenuItemDelete(m->item);
+}
+
 uiMenu *uiNewMenu(const char *name)
 {
 	uiMenu *m;
@@ -234,19 +411,34 @@ uiMenu *uiNewMenu(const char *name)
 	if (menus == NULL)
 		menus = g_array_new(FALSE, TRUE, sizeof (uiMenu *));
 
-	m = uiprivNew(uiMenu);
+	m = newMenu(name);
 
 	g_array_append_val(menus, m);
 
-	m->name = g_strdup(name);
-	m->items = g_array_new(FALSE, TRUE, sizeof (uiMenuItem *));
+	return m;
+}
+
+uiMenu *uiNewPopupMenu(void)
+{
+	uiMenu *m;
+
+	if (popups == NULL)
+		popups = g_array_new(FALSE, TRUE, sizeof (uiMenu *));
+
+	m = newMenu(NULL);
+	m->popup = TRUE;
+
+	g_array_append_val(popups, m);
 
 	return m;
 }
 
-static void appendMenuItem(GtkMenuShell *submenu, uiMenuItem *item, uiWindow *w)
+static void appendMenuItems(GtkMenuShell *submenu, GArray *items, uiWindow *w, GtkAccelGroup *accel);
+
+static void appendMenuItem(GtkMenuShell *submenu, uiMenuItem *item, uiWindow *w, GtkAccelGroup *accel)
 {
 	GtkWidget *menuitem;
+	GtkWidget *child;
 	gulong signal;
 	struct menuItemWindow *ww;
 
@@ -254,39 +446,61 @@ static void appendMenuItem(GtkMenuShell *submenu, uiMenuItem *item, uiWindow *w)
 	signal = 0;
 	if (item->name != NULL)
 		gtk_menu_item_set_label(GTK_MENU_ITEM(menuitem), item->name);
-	if (item->type != typeSeparator) {
+	ww = uiprivNew(struct menuItemWindow);
+	ww->w = w;
+	ww->accel = accel;
+	if (item->type == typeSubmenu) {
+		child = gtk_menu_new();
+		gtk_menu_set_accel_group(GTK_MENU(child), accel);
+		appendMenuItems(GTK_MENU_SHELL(child), item->submenu->items, w, accel);
+		gtk_menu_item_set_submenu(GTK_MENU_ITEM(menuitem), child);
+		gtk_widget_set_sensitive(menuitem, !item->disabled);
+	} else if (item->type != typeSeparator) {
 		signal = g_signal_connect(menuitem, "activate", G_CALLBACK(onClicked), item);
 		gtk_widget_set_sensitive(menuitem, !item->disabled);
 		if (item->type == typeCheckbox)
 			singleSetChecked(GTK_CHECK_MENU_ITEM(menuitem), item->checked, signal);
 	}
-	gtk_menu_shell_append(submenu, menuitem);
-	ww = uiprivNew(struct menuItemWindow);
-	ww->w = w;
 	ww->signal = signal;
+	setAccelerator(menuitem, item, ww, 0, 0);
+	gtk_menu_shell_append(submenu, menuitem);
 	g_hash_table_insert(item->windows, menuitem, ww);
 }
 
+static void appendMenuItems(GtkMenuShell *submenu, GArray *items, uiWindow *w, GtkAccelGroup *accel)
+{
+	guint i;
+
+	for (i = 0; i < items->len; i++)
+		appendMenuItem(submenu, g_array_index(items, uiMenuItem *, i), w, accel);
+}
+
 GtkWidget *uiprivMakeMenubar(uiWindow *w)
 {
 	GtkWidget *menubar;
-	guint i, j;
+	guint i;
 	uiMenu *m;
 	GtkWidget *menuitem;
 	GtkWidget *submenu;
+	GtkAccelGroup *accel;
 
 	menusFinalized = TRUE;
 
 	menubar = gtk_menu_bar_new();
 
+	// the window keeps the only reference
+	accel = gtk_accel_group_new();
+	gtk_window_add_accel_group(GTK_WINDOW(uiControlHandle(uiControl(w))), accel);
+	g_object_unref(accel);
+
 	if (menus != NULL)
 		for (i = 0; i < menus->len; i++) {
 			m = g_array_index(menus, uiMenu *, i);
 			menuitem = gtk_menu_item_new_with_label(m->name);
 			submenu = gtk_menu_new();
+			gtk_menu_set_accel_group(GTK_MENU(submenu), accel);
 			gtk_menu_item_set_submenu(GTK_MENU_ITEM(menuitem), submenu);
-			for (j = 0; j < m->items->len; j++)
-				appendMenuItem(GTK_MENU_SHELL(submenu), g_array_index(m->items, uiMenuItem *, j), w);
+			appendMenuItems(GTK_MENU_SHELL(submenu), m->items, w, accel);
 			gtk_menu_shell_append(GTK_MENU_SHELL(menubar), menuitem);
 		}
 
@@ -300,6 +514,8 @@ struct freeMenuItemData {
 	guint i;
 };
 
+static void freeMenuItems(GtkWidget *submenu, GArray *items);
+
 static void freeMenuItem(GtkWidget *widget, gpointer data)
 {
 	struct freeMenuItemData *fmi = (struct freeMenuItemData *) data;
@@ -307,6 +523,8 @@ static void freeMenuItem(GtkWidget *widget, gpointer data)
 	struct menuItemWindow *w;
 
 	item = g_array_index(fmi->items, uiMenuItem *, fmi->i);
+	if (item->type == typeSubmenu)
+		freeMenuItems(gtk_menu_item_get_submenu(GTK_MENU_ITEM(widget)), item->submenu->items);
 	w = (struct menuItemWindow *) g_hash_table_lookup(item->windows, widget);
 	if (g_hash_table_remove(item->windows, widget) == FALSE)
 		uiprivImplBug("GtkMenuItem %p not in menu item's item/window map", widget);
@@ -314,23 +532,154 @@ static void freeMenuItem(GtkWidget *widget, gpointer data)
 	fmi->i++;
 }
 
+static void freeMenuItems(GtkWidget *submenu, GArray *items)
+{
+	struct freeMenuItemData fmi;
+
+	fmi.items = items;
+	fmi.i = 0;
+	gtk_container_foreach(GTK_CONTAINER(submenu), freeMenuItem, &fmi);
+}
+
 static void freeMenu(GtkWidget *widget, gpointer data)
 {
 	guint *i = (guint *) data;
 	uiMenu *m;
-	GtkMenuItem *item;
-	GtkWidget *submenu;
-	struct freeMenuItemData fmi;
 
 	m = g_array_index(menus, uiMenu *, *i);
-	item = GTK_MENU_ITEM(widget);
-	submenu = gtk_menu_item_get_submenu(item);
-	fmi.items = m->items;
-	fmi.i = 0;
-	gtk_container_foreach(GTK_CONTAINER(submenu), freeMenuItem, &fmi);
+	freeMenuItems(gtk_menu_item_get_submenu(GTK_MENU_ITEM(widget)), m->items);
 	(*i)++;
 }
 
+struct popupData {
+	uiMenu *m;
+	GtkWidget *menu;
+	GtkWidget *widget;
+	int x;
+	int y;
+};
+
+static gboolean freePopup(gpointer data)
+{
+	struct popupData *p = (struct popupData *) data;
+
+	freeMenuItems(p->menu, p->m->items);
+	gtk_widget_destroy(p->menu);
+	uiprivFree(p);
+	return G_SOURCE_REMOVE;
+}
+
+static void onPopupDeactivate(GtkMenuShell *shell, gpointer data)
+{
+	// the chosen menu item is only activated after the menu is deactivated, so defer freeing the menu
+	g_idle_add(freePopup, data);
+}
+
+static void popupPosition(GtkMenu *menu, gint *x, gint *y, gboolean *pushIn, gpointer data)
+{
+	struct popupData *p = (struct popupData *) data;
+	GtkAllocation allocation;
+	gint originX, originY;
+
+	gdk_window_get_origin(gtk_widget_get_window(p->widget), &originX, &originY);
+	*x = originX + p->x;
+	*y = originY + p->y;
+	// widgets without their own GdkWindow are positioned relative to their parent's
+	if (!gtk_widget_get_has_window(p->widget)) {
+		gtk_widget_get_allocation(p->widget, &allocation);
+		*x += allocation.x;
+		*y += allocation.y;
+	}
+	*pushIn = TRUE;
+}
+
+static uiWindow *toplevelWindow(uiControl *c)
+{
+	while (c != NULL && !uiControlToplevel(c))
+		c = uiControlParent(c);
+	return uiWindow(c);
+}
+
+static int popup(uiMenu *m, uiControl *c, gboolean atPointer, int x, int y)
+{
+	struct popupData *p;
+	uiWindow *w;
+
+	if (!m->popup || m->item != NULL)
+		uiprivUserBug("You can only pop up menus created with uiNewPopupMenu().");
+	// item callbacks need the window; controls not inside one yet cannot show menus
+	w = toplevelWindow(c);
+	if (w == NULL)
+		return FALSE;
+
+	p = uiprivNew(struct popupData);
+	p->m = m;
+	p->widget = GTK_WIDGET(uiControlHandle(c));
+	p->x = x;
+	p->y = y;
+	p->menu = gtk_menu_new();
+	appendMenuItems(GTK_MENU_SHELL(p->menu), m->items, w, NULL);
+	gtk_menu_attach_to_widget(GTK_MENU(p->menu), p->widget, NULL);
+	g_signal_connect(p->menu, "deactivate", G_CALLBACK(onPopupDeactivate), p);
+	gtk_widget_show_all(p->menu);
+	gtk_menu_popup(GTK_MENU(p->menu), NULL, NULL,
+		atPointer ? NULL : popupPosition, p,
+		0, gtk_get_current_event_time());
+	return TRUE;
+}
+
+int uiMenuPopup(uiMenu *m, uiControl *c)
+{
+	return popup(m, c, TRUE, 0, 0);
+}
+
+int uiMenuPopupAt(uiMenu *m, uiControl *c, int x, int y)
+{
+	return popup(m, c, FALSE, x, y);
+}
+
+static gboolean onContextButtonPress(GtkWidget *widget, GdkEventButton *e, gpointer data)
+{
+	uiMenu *m;
+
+	m = (uiMenu *) g_object_get_data(G_OBJECT(widget), "libui-context-menu");
+	if (m == NULL || !gdk_event_triggers_context_menu((GdkEvent *) e))
+		return GDK_EVENT_PROPAGATE;
+	popup(m, uiControl(data), TRUE, 0, 0);
+	return GDK_EVENT_STOP;
+}
+
+// keyboard invocation (Menu key, Shift+F10) has no pointer position; show the menu at the control instead
+static gboolean onContextPopupMenu(GtkWidget *widget, gpointer data)
+{
+	uiMenu *m;
+
+	m = (uiMenu *) g_object_get_data(G_OBJECT(widget), "libui-context-menu");
+	if (m == NULL)
+		return FALSE;
+	popup(m, uiControl(data), FALSE, 0, 0);
+	// returning TRUE keeps the widget's own context menu from showing too
+	return TRUE;
+}
+
+void uiControlSetContextMenu(uiControl *c, uiMenu *m)
+{
+	GtkWidget *widget;
+
+	if (m != NULL && (!m->popup || m->item != NULL))
+		uiprivUserBug("You can only use menus created with uiNewPopupMenu() as context menus.");
+
+	// connect on the inner widget of scrolled controls, so that the built-in menu of e.g. GtkTextView is replaced
+	widget = uiprivControlWidget(c);
+	if (g_object_get_data(G_OBJECT(widget), "libui-context-menu-connected") == NULL) {
+		gtk_widget_add_events(widget, GDK_BUTTON_PRESS_MASK);
+		g_signal_connect(widget, "button-press-event", G_CALLBACK(onContextButtonPress), c);
+		g_signal_connect(widget, "popup-menu", G_CALLBACK(onContextPopupMenu), c);
+		g_object_set_data(G_OBJECT(widget), "libui-context-menu-connected", GINT_TO_POINTER(TRUE));
+	}
+	g_object_set_data(G_OBJECT(widget), "libui-context-menu", m);
+}
+
 void uiprivFreeMenubar(GtkWidget *mb)
 {
 	guint i;
@@ -343,24 +692,24 @@ void uiprivFreeMenubar(GtkWidget *mb)
 void uiprivUninitMenus(void)
 {
 	uiMenu *m;
-	uiMenuItem *item;
-	guint i, j;
+	guint i;
+
+	if (popups != NULL) {
+		for (i = 0; i < popups->len; i++) {
+			m = g_array_index(popups, uiMenu *, i);
+			freeItems(m->items);
+			uiprivFree(m);
+		}
+		g_array_free(popups, TRUE);
+		popups = NULL;
+	}
 
 	if (menus == NULL)
 		return;
 	for (i = 0; i < menus->len; i++) {
 		m = g_array_index(menus, uiMenu *, i);
 		g_free(m->name);
-		for (j = 0; j < m->items->len; j++) {
-			item = g_array_index(m->items, uiMenuItem *, j);
-			if (g_hash_table_size(item->windows) != 0)
-				// TODO is this really a uiprivUserBug()?
-				uiprivImplBug("menu item %p (%s) still has uiWindows attached; did you forget to destroy some windows?", item, item->name);
-			g_free(item->name);
-			g_hash_table_destroy(item->windows);
-			uiprivFree(item);
-		}
-		g_array_free(m->items, TRUE);
+		freeItems(m->items);
 		uiprivFree(m);
 	}
 	g_array_free(menus, TRUE);
@@ -14,6 +14,9 @@ struct uiMultilineEntry {
 	void (*onChanged)(uiMultilineEntry *, void *);
 	void *onChangedData;
 	gulong onChangedSignal;
+	// stays at the end of the text because of its right gravity
+	GtkTextMark *endMark;
+	gboolean autoScroll;
 };
 
 uiUnixControlAllDefaults(uiMultilineEntry)
@@ -48,7 +51,6 @@ void uiMultilineEntrySetText(uiMultilineEntry *e, const char *text)
 	g_signal_handler_unblock(e->textbuf, e->onChangedSignal);
 }
 
-// TODO scroll to end?
 void uiMultilineEntryAppend(uiMultilineEntry *e, const char *text)
 {
 	GtkTextIter end;
@@ -58,6 +60,159 @@ void uiMultilineEntryAppend(uiMultilineEntry *e, const char *text)
 	g_signal_handler_block(e->textbuf, e->onChangedSignal);
 	gtk_text_buffer_insert(e->textbuf, &end, text, -1);
 	g_signal_handler_unblock(e->textbuf, e->onChangedSignal);
+	if (e->autoScroll)
+		uiMultilineEntryScrollToEnd(e);
+}
+
+void uiMultilineEntryAppendWithColor(uiMultilineEntry *e, const char *text, double r, double g, double b, double a)
+{
+	GtkTextTag *tag;
+	GtkTextIter end;
+	GdkRGBA color;
+	char *name;
+
+	// share one tag per color instead of creating one per call
+	name = g_strdup_printf("libui-color-%g-%g-%g-%g", r, g, b, a);
+	tag = gtk_text_tag_table_lookup(gtk_text_buffer_get_tag_table(e->textbuf), name);
+	if (tag == NULL) {
+		color.red = r;
+		color.green = g;
+		color.blue = b;
+		color.alpha = a;
+		tag = gtk_text_buffer_create_tag(e->textbuf, name, "foreground-rgba", &color, NULL);
+	}
+	g_free(name);
+
+	gtk_text_buffer_get_end_iter(e->textbuf, &end);
+	g_signal_handler_block(e->textbuf, e->onChangedSignal);
+	gtk_text_buffer_insert_with_tags(e->textbuf, &end, text, -1, tag, NULL);
+	g_signal_handler_unblock(e->textbuf, e->onChangedSignal);
+	if (e->autoScroll)
+		uiMultilineEntryScrollToEnd(e);
+}
+
+void uiMultilineEntryDeleteLines(uiMultilineEntry *e, int start, int count)
+{
+	GtkTextIter startIter, endIter;
+
+	// lines past the last one yield the end of the text
+	gtk_text_buffer_get_iter_at_line(e->textbuf, &startIter, start);
+	gtk_text_buffer_get_iter_at_line(e->textbuf, &endIter, start + count);
+	g_signal_handler_block(e->textbuf, e->onChangedSignal);
+	gtk_text_buffer_delete(e->textbuf, &startIter, &endIter);
+	g_signal_handler_unblock(e->textbuf, e->onChangedSignal);
+}
+
+void uiMultilineEntryInsertAtCursor(uiMultilineEntry *e, const char *text)
+{
+	// we need to inhibit sending of ::changed because this WILL send a ::changed otherwise
+	g_signal_handler_block(e->textbuf, e->onChangedSignal);
+	gtk_text_buffer_insert_at_cursor(e->textbuf, text, -1);
+	g_signal_handler_unblock(e->textbuf, e->onChangedSignal);
+}
+
+int uiMultilineEntryCursorPosition(uiMultilineEntry *e)
+{
+	GtkTextIter iter;
+
+	gtk_text_buffer_get_iter_at_mark(e->textbuf, &iter, gtk_text_buffer_get_insert(e->textbuf));
+	return gtk_text_iter_get_offset(&iter);
+}
+
+void uiMultilineEntrySetCursorPosition(uiMultilineEntry *e, int pos)
+{
+	GtkTextIter iter;
+
+	gtk_text_buffer_get_iter_at_offset(e->textbuf, &iter, pos);
+	gtk_text_buffer_place_cursor(e->textbuf, &iter);
+}
+
+void uiMultilineEntrySelectRange(uiMultilineEntry *e, int start, int end)
+{
+	GtkTextIter startIter, endIter;
+
+	// negative offsets mean the end of the text
+	gtk_text_buffer_get_iter_at_offset(e->textbuf, &startIter, start);
+	gtk_text_buffer_get_iter_at_offset(e->textbuf, &endIter, end);
+	// the cursor ends up at the end of the selection, like with uiEntry
+	gtk_text_buffer_select_range(e->textbuf, &endIter, &startIter);
+}
+
+int uiMultilineEntrySelection(uiMultilineEntry *e, int *start, int *end)
+{
+	GtkTextIter startIter, endIter;
+	gboolean selected;
+
+	selected = gtk_text_buffer_get_selection_bounds(e->textbuf, &startIter, &endIter);
+	*start = gtk_text_iter_get_offset(&startIter);
+	*end = gtk_text_iter_get_offset(&endIter);
+	return selected != FALSE;
+}
+
+void uiMultilineEntryScrollToEnd(uiMultilineEntry *e)
+{
+	gtk_text_view_scroll_mark_onscreen(e->textview, e->endMark);
+}
+
+int uiMultilineEntryAutoScroll(uiMultilineEntry *e)
+{
+	return e->autoScroll;
+}
+
+void uiMultilineEntrySetAutoScroll(uiMultilineEntry *e, int autoScroll)
+{
+	e->autoScroll = autoScroll != 0;
+}
+
+int uiMultilineEntryLineCount(uiMultilineEntry *e)
+{
+	return gtk_text_buffer_get_line_count(e->textbuf);
+}
+
+char *uiMultilineEntryLine(uiMultilineEntry *e, int line)
+{
+	GtkTextIter start, end;
+
+	if (line < 0 || line >= gtk_text_buffer_get_line_count(e->textbuf))
+		return NULL;
+	gtk_text_buffer_get_iter_at_line(e->textbuf, &start, line);
+	end = start;
+	if (!gtk_text_iter_ends_line(&end))
+		gtk_text_iter_forward_to_line_end(&end);
+	return gtk_text_buffer_get_text(e->textbuf, &start, &end, TRUE);
+}
+
+int uiMultilineEntryLineColor(uiMultilineEntry *e, int line, double *r, double *g, double *b, double *a)
+{
+	GtkTextIter start;
+	GSList *tags, *l;
+	GdkRGBA *color, *found;
+	gboolean set;
+
+	if (line < 0 || line >= gtk_text_buffer_get_line_count(e->textbuf))
+		return FALSE;
+	gtk_text_buffer_get_iter_at_line(e->textbuf, &start, line);
+	// tags are sorted by ascending priority, so the last color set wins
+	found = NULL;
+	tags = gtk_text_iter_get_tags(&start);
+	for (l = tags; l != NULL; l = l->next) {
+		g_object_get(l->data, "foreground-set", &set, NULL);
+		if (!set)
+			continue;
+		g_object_get(l->data, "foreground-rgba", &color, NULL);
+		if (found != NULL)
+			gdk_rgba_free(found);
+		found = color;
+	}
+	g_slist_free(tags);
+	if (found == NULL)
+		return FALSE;
+	*r = found->red;
+	*g = found->green;
+	*b = found->blue;
+	*a = found->alpha;
+	gdk_rgba_free(found);
+	return TRUE;
 }
 
 void uiMultilineEntryOnChanged(uiMultilineEntry *e, void (*f)(uiMultilineEntry *e, void *data), void *data)
@@ -84,6 +239,7 @@ void uiMultilineEntrySetReadOnly(uiMultilineEntry *e, int readonly)
 static uiMultilineEntry *finishMultilineEntry(GtkPolicyType hpolicy, GtkWrapMode wrapMode)
 {
 	uiMultilineEntry *e;
+	GtkTextIter end;
 
 	uiUnixNewControl(uiMultilineEntry, e);
 
@@ -104,6 +260,8 @@ static uiMultilineEntry *finishMultilineEntry(GtkPolicyType hpolicy, GtkWrapMode
 	gtk_widget_show(e->textviewWidget);
 
 	e->textbuf = gtk_text_view_get_buffer(e->textview);
+	gtk_text_buffer_get_end_iter(e->textbuf, &end);
+	e->endMark = gtk_text_buffer_create_mark(e->textbuf, NULL, &end, FALSE);
 
 	e->onChangedSignal = g_signal_connect(e->textbuf, "changed", G_CALLBACK(onChanged), e);
 	uiMultilineEntryOnChanged(e, defaultOnChanged, NULL);
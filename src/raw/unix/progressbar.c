@@ -61,6 +61,32 @@ void uiProgressBarSetValue(uiProgressBar *p, int value)
 	gtk_progress_bar_set_fraction(p->pbar, ((gdouble) value) / 100);
 }
 
+char *uiProgressBarText(uiProgressBar *p)
+{
+	const char *text;
+
+	text = gtk_progress_bar_get_text(p->pbar);
+	if (text == NULL)
+		text = "";
+	return uiUnixStrdupText(text);
+}
+
+void uiProgressBarSetText(uiProgressBar *p, const char *text)
+{
+	// NULL makes GTK+ show the percentage
+	gtk_progress_bar_set_text(p->pbar, text);
+}
+
+int uiProgressBarShowText(uiProgressBar *p)
+{
+	return gtk_progress_bar_get_show_text(p->pbar) != FALSE;
+}
+
+void uiProgressBarSetShowText(uiProgressBar *p, int show)
+{
+	gtk_progress_bar_set_show_text(p->pbar, show);
+}
+
 uiProgressBar *uiNewProgressBar(void)
 {
 	uiProgressBar *p;
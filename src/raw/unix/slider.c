@@ -1,12 +1,6 @@
 // 11 june 2015
 #include "uipriv_unix.h"
 
-/* Number of digits to represent an n-bit number in decimal: log10(2^n)
- * Simplify, truncate & add 1 for full digits, add 1 for negative numbers:
- *   -> n log10(2) + 2   <=   n * 0.302 + 2
- */
-#define MAX_STRLEN_FOR_NBITS_IN_DECIMAL(n) ((int)((n) * 302 / 1000) + 2)
-
 struct uiSlider {
 	uiUnixControl c;
 	GtkWidget *widget;
@@ -17,15 +11,18 @@ struct uiSlider {
 	void (*onReleased)(uiSlider *, void *);
 	void *onReleasedData;
 	gulong onChangedSignal;
-	gchar tooltip[MAX_STRLEN_FOR_NBITS_IN_DECIMAL(sizeof(int) * CHAR_BIT) + 1];
 };
 
 uiUnixControlAllDefaults(uiSlider)
 
 static void _uiSliderUpdateToolTip(uiSlider *s)
 {
-	g_snprintf(s->tooltip, sizeof(s->tooltip)/sizeof(s->tooltip[0]), "%d", uiSliderValue(s));
-	gtk_widget_set_tooltip_text(s->widget, s->tooltip);
+	char *tooltip;
+
+	// show as many decimals as the slider rounds to
+	tooltip = g_strdup_printf("%.*f", gtk_scale_get_digits(s->scale), uiSliderValueDouble(s));
+	gtk_widget_set_tooltip_text(s->widget, tooltip);
+	g_free(tooltip);
 }
 
 static void onChanged(GtkRange *range, gpointer data)
@@ -62,11 +59,23 @@ int uiSliderValue(uiSlider *s)
 }
 
 void uiSliderSetValue(uiSlider *s, int value)
+{
+	uiSliderSetValueDouble(s, value);
+}
+
+double uiSliderValueDouble(uiSlider *s)
+{
+	return gtk_range_get_value(s->range);
+}
+
+void uiSliderSetValueDouble(uiSlider *s, double value)
 {
 	// we need to inhibit sending of ::value-changed because this WILL send a ::value-changed otherwise
 	g_signal_handler_block(s->range, s->onChangedSignal);
 	gtk_range_set_value(s->range, value);
 	g_signal_handler_unblock(s->range, s->onChangedSignal);
+	if (uiSliderHasToolTip(s))
+		_uiSliderUpdateToolTip(s);
 }
 
 int uiSliderHasToolTip(uiSlider *s)
@@ -96,7 +105,12 @@ void uiSliderOnReleased(uiSlider *s, void (*f)(uiSlider *, void *), void *data)
 
 void uiSliderSetRange(uiSlider *s, int min, int max)
 {
-	int temp;
+	uiSliderSetRangeDouble(s, min, max);
+}
+
+void uiSliderSetRangeDouble(uiSlider *s, double min, double max)
+{
+	double temp;
 
 	if (min >= max) {
 		temp = min;
@@ -110,10 +124,62 @@ void uiSliderSetRange(uiSlider *s, int min, int max)
 	g_signal_handler_unblock(s->range, s->onChangedSignal);
 }
 
-uiSlider *uiNewSlider(int min, int max)
+double uiSliderStep(uiSlider *s)
+{
+	return gtk_adjustment_get_step_increment(gtk_range_get_adjustment(s->range));
+}
+
+double uiSliderPage(uiSlider *s)
+{
+	return gtk_adjustment_get_page_increment(gtk_range_get_adjustment(s->range));
+}
+
+void uiSliderSetIncrements(uiSlider *s, double step, double page)
+{
+	gtk_range_set_increments(s->range, step, page);
+}
+
+int uiSliderPrecision(uiSlider *s)
+{
+	return gtk_scale_get_digits(s->scale);
+}
+
+void uiSliderSetPrecision(uiSlider *s, int digits)
+{
+	// this also rounds the values the user picks
+	gtk_scale_set_digits(s->scale, digits);
+	if (uiSliderHasToolTip(s))
+		_uiSliderUpdateToolTip(s);
+}
+
+int uiSliderVertical(uiSlider *s)
+{
+	return gtk_orientable_get_orientation(GTK_ORIENTABLE(s->widget)) == GTK_ORIENTATION_VERTICAL;
+}
+
+void uiSliderSetVertical(uiSlider *s, int vertical)
+{
+	gtk_orientable_set_orientation(GTK_ORIENTABLE(s->widget),
+		vertical ? GTK_ORIENTATION_VERTICAL : GTK_ORIENTATION_HORIZONTAL);
+	// vertical sliders grow upwards, like faders
+	gtk_range_set_inverted(s->range, vertical);
+}
+
+void uiSliderAddTick(uiSlider *s, double value, const char *label)
+{
+	// vertical scales treat GTK_POS_BOTTOM as the right side
+	gtk_scale_add_mark(s->scale, value, GTK_POS_BOTTOM, label);
+}
+
+void uiSliderClearTicks(uiSlider *s)
+{
+	gtk_scale_clear_marks(s->scale);
+}
+
+static uiSlider *newSlider(double min, double max, double step)
 {
 	uiSlider *s;
-	int temp;
+	double temp;
 
 	if (min >= max) {
 		temp = min;
@@ -123,7 +189,8 @@ uiSlider *uiNewSlider(int min, int max)
 
 	uiUnixNewControl(uiSlider, s);
 
-	s->widget = gtk_scale_new_with_range(GTK_ORIENTATION_HORIZONTAL, min, max, 1);
+	// this picks the precision from the step
+	s->widget = gtk_scale_new_with_range(GTK_ORIENTATION_HORIZONTAL, min, max, step);
 	s->range = GTK_RANGE(s->widget);
 	s->scale = GTK_SCALE(s->widget);
 
@@ -131,9 +198,6 @@ uiSlider *uiNewSlider(int min, int max)
 	gtk_scale_set_draw_value(s->scale, 0);
 	uiSliderSetHasToolTip(s, 1);
 
-	// ensure integers, just to be safe
-	gtk_scale_set_digits(s->scale, 0);
-
 	s->onChangedSignal = g_signal_connect(s->scale, "value-changed", G_CALLBACK(onChanged), s);
 	g_signal_connect(s->scale, "button-release-event", G_CALLBACK(onReleased), s);
 	uiSliderOnChanged(s, defaultOnChanged, NULL);
@@ -141,3 +205,18 @@ uiSlider *uiNewSlider(int min, int max)
 
 	return s;
 }
+
+uiSlider *uiNewSlider(int min, int max)
+{
+	uiSlider *s;
+
+	s = newSlider(min, max, 1);
+	// ensure integers, just to be safe
+	uiSliderSetPrecision(s, 0);
+	return s;
+}
+
+uiSlider *uiNewSliderDouble(double min, double max, double step)
+{
+	return newSlider(min, max, step);
+}
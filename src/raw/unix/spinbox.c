@@ -31,24 +31,87 @@ int uiSpinboxValue(uiSpinbox *s)
 }
 
 void uiSpinboxSetValue(uiSpinbox *s, int value)
+{
+	uiSpinboxSetValueDouble(s, value);
+}
+
+double uiSpinboxValueDouble(uiSpinbox *s)
+{
+	return gtk_spin_button_get_value(s->spinButton);
+}
+
+void uiSpinboxSetValueDouble(uiSpinbox *s, double value)
 {
 	// we need to inhibit sending of ::value-changed because this WILL send a ::value-changed otherwise
 	g_signal_handler_block(s->spinButton, s->onChangedSignal);
 	// this clamps for us
-	gtk_spin_button_set_value(s->spinButton, (gdouble) value);
+	gtk_spin_button_set_value(s->spinButton, value);
+	g_signal_handler_unblock(s->spinButton, s->onChangedSignal);
+}
+
+void uiSpinboxSetRange(uiSpinbox *s, int min, int max)
+{
+	uiSpinboxSetRangeDouble(s, min, max);
+}
+
+void uiSpinboxSetRangeDouble(uiSpinbox *s, double min, double max)
+{
+	double temp;
+
+	if (min >= max) {
+		temp = min;
+		min = max;
+		max = temp;
+	}
+
+	// we need to inhibit sending of ::value-changed because this WILL send a ::value-changed otherwise
+	g_signal_handler_block(s->spinButton, s->onChangedSignal);
+	// this clamps the value for us
+	gtk_spin_button_set_range(s->spinButton, min, max);
 	g_signal_handler_unblock(s->spinButton, s->onChangedSignal);
 }
 
+double uiSpinboxStep(uiSpinbox *s)
+{
+	double step, page;
+
+	gtk_spin_button_get_increments(s->spinButton, &step, &page);
+	return step;
+}
+
+double uiSpinboxPage(uiSpinbox *s)
+{
+	double step, page;
+
+	gtk_spin_button_get_increments(s->spinButton, &step, &page);
+	return page;
+}
+
+void uiSpinboxSetIncrements(uiSpinbox *s, double step, double page)
+{
+	gtk_spin_button_set_increments(s->spinButton, step, page);
+}
+
+int uiSpinboxPrecision(uiSpinbox *s)
+{
+	return gtk_spin_button_get_digits(s->spinButton);
+}
+
+void uiSpinboxSetPrecision(uiSpinbox *s, int digits)
+{
+	gtk_spin_button_set_digits(s->spinButton, digits);
+}
+
 void uiSpinboxOnChanged(uiSpinbox *s, void (*f)(uiSpinbox *, void *), void *data)
 {
 	s->onChanged = f;
 	s->onChangedData = data;
 }
 
-uiSpinbox *uiNewSpinbox(int min, int max)
+static uiSpinbox *newSpinbox(double min, double max, double step)
 {
 	uiSpinbox *s;
-	int temp;
+	double temp;
 
 	if (min >= max) {
 		temp = min;
@@ -58,15 +121,28 @@ uiSpinbox *uiNewSpinbox(int min, int max)
 
 	uiUnixNewControl(uiSpinbox, s);
 
-	s->widget = gtk_spin_button_new_with_range(min, max, 1);
+	// this picks the precision from the step
+	s->widget = gtk_spin_button_new_with_range(min, max, step);
 	s->entry = GTK_ENTRY(s->widget);
 	s->spinButton = GTK_SPIN_BUTTON(s->widget);
 
-	// ensure integers, just to be safe
-	gtk_spin_button_set_digits(s->spinButton, 0);
-
 	s->onChangedSignal = g_signal_connect(s->spinButton, "value-changed", G_CALLBACK(onChanged), s);
 	uiSpinboxOnChanged(s, defaultOnChanged, NULL);
 
 	return s;
 }
+
+uiSpinbox *uiNewSpinbox(int min, int max)
+{
+	uiSpinbox *s;
+
+	s = newSpinbox(min, max, 1);
+	// ensure integers, just to be safe
+	gtk_spin_button_set_digits(s->spinButton, 0);
+	return s;
+}
+
+uiSpinbox *uiNewSpinboxDouble(double min, double max, double step)
+{
+	return newSpinbox(min, max, step);
+}
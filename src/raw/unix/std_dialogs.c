@@ -48,6 +48,104 @@ char *uiSaveFile(uiWindow *parent)
 	return filedialog(windowWindow(parent), GTK_FILE_CHOOSER_ACTION_SAVE, "_Save");
 }
 
+static void addFilters(GtkFileChooser *fc, const uiFileDialogParams *params)
+{
+	GtkFileFilter *filter;
+	size_t i, j;
+
+	for (i = 0; i < params->NumFilters; i++) {
+		filter = gtk_file_filter_new();
+		gtk_file_filter_set_name(filter, params->Filters[i].Name);
+		for (j = 0; j < params->Filters[i].NumPatterns; j++)
+			gtk_file_filter_add_pattern(filter, params->Filters[i].Patterns[j]);
+		// the chooser takes ownership of the floating reference
+		gtk_file_chooser_add_filter(fc, filter);
+	}
+}
+
+char **uiFileDialog(uiWindow *parent, uiFileDialogMode mode, const uiFileDialogParams *params)
+{
+	GtkWidget *fcd;
+	GtkFileChooser *fc;
+	GtkFileChooserAction action;
+	const gchar *confirm;
+	GSList *filenames, *l;
+	char **paths;
+	char *path, *cwd;
+	gint response;
+	guint i;
+
+	switch (mode) {
+	case uiFileDialogModeOpenFolder:
+		action = GTK_FILE_CHOOSER_ACTION_SELECT_FOLDER;
+		confirm = "_Open";
+		break;
+	case uiFileDialogModeSaveFile:
+		action = GTK_FILE_CHOOSER_ACTION_SAVE;
+		confirm = "_Save";
+		break;
+	default:
+		action = GTK_FILE_CHOOSER_ACTION_OPEN;
+		confirm = "_Open";
+		break;
+	}
+
+	fcd = gtk_file_chooser_dialog_new(params->Title, windowWindow(parent), action,
+		"_Cancel", GTK_RESPONSE_CANCEL,
+		confirm, GTK_RESPONSE_ACCEPT,
+		NULL);
+	fc = GTK_FILE_CHOOSER(fcd);
+	gtk_file_chooser_set_local_only(fc, FALSE);
+	gtk_file_chooser_set_select_multiple(fc, mode != uiFileDialogModeSaveFile && params->MultipleSelection);
+	gtk_file_chooser_set_show_hidden(fc, params->ShowHidden != 0);
+	gtk_file_chooser_set_do_overwrite_confirmation(fc, TRUE);
+	gtk_file_chooser_set_create_folders(fc, TRUE);
+	addFilters(fc, params);
+	if (params->Directory != NULL)
+		gtk_file_chooser_set_current_folder(fc, params->Directory);
+	if (params->FileName != NULL) {
+		if (mode == uiFileDialogModeSaveFile) {
+			// the name is UTF-8 here, not in the file name encoding
+			path = g_filename_display_name(params->FileName);
+			gtk_file_chooser_set_current_name(fc, path);
+			g_free(path);
+		} else {
+			if (params->Directory != NULL)
+				path = g_build_filename(params->Directory, params->FileName, NULL);
+			else if (g_path_is_absolute(params->FileName))
+				path = g_strdup(params->FileName);
+			else {
+				cwd = g_get_current_dir();
+				path = g_build_filename(cwd, params->FileName, NULL);
+				g_free(cwd);
+			}
+			gtk_file_chooser_set_filename(fc, path);
+			g_free(path);
+		}
+	}
+
+	response = gtk_dialog_run(GTK_DIALOG(fcd));
+	if (response != GTK_RESPONSE_ACCEPT) {
+		gtk_widget_destroy(fcd);
+		return NULL;
+	}
+	filenames = gtk_file_chooser_get_filenames(fc);
+	gtk_widget_destroy(fcd);
+
+	paths = g_new0(char *, g_slist_length(filenames) + 1);
+	i = 0;
+	// the strings are moved into the array; only the list nodes are freed
+	for (l = filenames; l != NULL; l = l->next)
+		paths[i++] = (char *) (l->data);
+	g_slist_free(filenames);
+	return paths;
+}
+
+void uiFreeFileDialogPaths(char **paths)
+{
+	g_strfreev(paths);
+}
+
 static void msgbox(GtkWindow *parent, const char *title, const char *description, GtkMessageType type, GtkButtonsType buttons)
 {
 	GtkWidget *md;
@@ -69,3 +167,63 @@ void uiMsgBoxError(uiWindow *parent, const char *title, const char *description)
 {
 	msgbox(windowWindow(parent), title, description, GTK_MESSAGE_ERROR, GTK_BUTTONS_OK);
 }
+
+int uiMessageDialog(uiWindow *parent, uiMessageDialogIcon icon, const char *title, const char *description, const char * const *buttons, int numButtons, int defaultButton, int cancelButton)
+{
+	GtkWidget *md;
+	GtkMessageType type;
+	gint response;
+	int i;
+
+	switch (icon) {
+	case uiMessageDialogIconWarning:
+		type = GTK_MESSAGE_WARNING;
+		break;
+	case uiMessageDialogIconError:
+		type = GTK_MESSAGE_ERROR;
+		break;
+	case uiMessageDialogIconQuestion:
+		type = GTK_MESSAGE_QUESTION;
+		break;
+	default:
+		type = GTK_MESSAGE_INFO;
+		break;
+	}
+
+	md = gtk_message_dialog_new(windowWindow(parent), GTK_DIALOG_MODAL,
+		type, GTK_BUTTONS_NONE,
+		"%s", title);
+	gtk_message_dialog_format_secondary_text(GTK_MESSAGE_DIALOG(md), "%s", description);
+	if (numButtons == 0) {
+		gtk_dialog_add_button(GTK_DIALOG(md), "_OK", 0);
+		defaultButton = 0;
+		cancelButton = 0;
+	}
+	// button indices double as response IDs; GTK's own responses are all negative
+	for (i = 0; i < numButtons; i++)
+		gtk_dialog_add_button(GTK_DIALOG(md), buttons[i], i);
+	if (defaultButton >= 0)
+		gtk_dialog_set_default_response(GTK_DIALOG(md), defaultButton);
+	response = gtk_dialog_run(GTK_DIALOG(md));
+	gtk_widget_destroy(md);
+	if (response < 0)
+		// closed via the window manager or Escape
+		return cancelButton;
+	return response;
+}
+
+int uiMsgBoxConfirm(uiWindow *parent, const char *title, const char *description)
+{
+	GtkWidget *md;
+	gint response;
+
+	// GTK provides the translated labels of the stock buttons
+	md = gtk_message_dialog_new(windowWindow(parent), GTK_DIALOG_MODAL,
+		GTK_MESSAGE_QUESTION, GTK_BUTTONS_OK_CANCEL,
+		"%s", title);
+	gtk_message_dialog_format_secondary_text(GTK_MESSAGE_DIALOG(md), "%s", description);
+	gtk_dialog_set_default_response(GTK_DIALOG(md), GTK_RESPONSE_OK);
+	response = gtk_dialog_run(GTK_DIALOG(md));
+	gtk_widget_destroy(md);
+	return response == GTK_RESPONSE_OK;
+}
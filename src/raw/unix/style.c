@@ -0,0 +1,198 @@
+// 19 october 2026
+#include "uipriv_unix.h"
+#include "attr_str.h"
+
+// every styled widget gets its own provider; its CSS is rebuilt from the parts below on each change
+struct controlStyle {
+	GtkCssProvider *provider;
+	char *font;
+	char *color;
+	char *background;
+};
+
+static void freeStyle(gpointer data)
+{
+	struct controlStyle *s = (struct controlStyle *) data;
+
+	g_object_unref(s->provider);
+	g_free(s->font);
+	g_free(s->color);
+	g_free(s->background);
+	uiprivFree(s);
+}
+
+static struct controlStyle *controlStyle(uiControl *c)
+{
+	GtkWidget *widget;
+	struct controlStyle *s;
+
+	widget = uiprivControlWidget(c);
+	s = (struct controlStyle *) g_object_get_data(G_OBJECT(widget), "libui-style");
+	if (s != NULL)
+		return s;
+
+	s = uiprivNew(struct controlStyle);
+	s->provider = gtk_css_provider_new();
+	gtk_style_context_add_provider(gtk_widget_get_style_context(widget),
+		GTK_STYLE_PROVIDER(s->provider),
+		GTK_STYLE_PROVIDER_PRIORITY_APPLICATION);
+	g_object_set_data_full(G_OBJECT(widget), "libui-style", s, freeStyle);
+	return s;
+}
+
+static void updateStyle(struct controlStyle *s)
+{
+	GString *css;
+
+	css = g_string_new("* {\n");
+	if (s->font != NULL)
+		g_string_append(css, s->font);
+	if (s->color != NULL)
+		g_string_append(css, s->color);
+	if (s->background != NULL)
+		g_string_append(css, s->background);
+	g_string_append(css, "}\n");
+	gtk_css_provider_load_from_data(s->provider, css->str, -1, NULL);
+	g_string_free(css, TRUE);
+}
+
+// CSS wants a dot as decimal separator regardless of the locale
+static char *cssColor(const char *property, double r, double g, double b, double a)
+{
+	char alpha[G_ASCII_DTOSTR_BUF_SIZE];
+
+	g_ascii_formatd(alpha, G_ASCII_DTOSTR_BUF_SIZE, "%.3f", a);
+	return g_strdup_printf("\t%s: rgba(%d, %d, %d, %s);\n", property,
+		(int) (r * 255), (int) (g * 255), (int) (b * 255), alpha);
+}
+
+static const char *cssStyles[] = {
+	[uiTextItalicNormal] = "normal",
+	[uiTextItalicOblique] = "oblique",
+	[uiTextItalicItalic] = "italic",
+};
+
+static const char *cssStretches[] = {
+	[uiTextStretchUltraCondensed] = "ultra-condensed",
+	[uiTextStretchExtraCondensed] = "extra-condensed",
+	[uiTextStretchCondensed] = "condensed",
+	[uiTextStretchSemiCondensed] = "semi-condensed",
+	[uiTextStretchNormal] = "normal",
+	[uiTextStretchSemiExpanded] = "semi-expanded",
+	[uiTextStretchExpanded] = "expanded",
+	[uiTextStretchExtraExpanded] = "extra-expanded",
+	[uiTextStretchUltraExpanded] = "ultra-expanded",
+};
+
+// the longhands avoid the font shorthand, which resets unrelated properties and does not take Pango's syntax
+static char *cssFont(const uiFontDescriptor *font)
+{
+	GString *css;
+	const char *p;
+	char size[G_ASCII_DTOSTR_BUF_SIZE];
+	int weight;
+
+	css = g_string_new("\tfont-family: \"");
+	for (p = font->Family; *p != '\0'; p++)
+		switch (*p) {
+		case '"':
+		case '\\':
+			g_string_append_c(css, '\\');
+			g_string_append_c(css, *p);
+			break;
+		case '\n':
+			g_string_append(css, "\\a ");
+			break;
+		default:
+			g_string_append_c(css, *p);
+		}
+	g_string_append(css, "\";\n");
+
+	g_ascii_formatd(size, G_ASCII_DTOSTR_BUF_SIZE, "%g", font->Size);
+	g_string_append_printf(css, "\tfont-size: %spt;\n", size);
+	// older GTK+ 3 versions only take multiples of 100 up to 900
+	weight = CLAMP(((int) font->Weight + 50) / 100 * 100, 100, 900);
+	g_string_append_printf(css, "\tfont-weight: %d;\n", weight);
+	g_string_append_printf(css, "\tfont-style: %s;\n", cssStyles[font->Italic]);
+	g_string_append_printf(css, "\tfont-stretch: %s;\n", cssStretches[font->Stretch]);
+	return g_string_free(css, FALSE);
+}
+
+void uiControlSetFont(uiControl *c, const uiFontDescriptor *font)
+{
+	struct controlStyle *s;
+
+	s = controlStyle(c);
+	g_free(s->font);
+	s->font = NULL;
+	if (font != NULL)
+		s->font = cssFont(font);
+	updateStyle(s);
+}
+
+void uiControlFont(uiControl *c, uiFontDescriptor *font)
+{
+	GtkWidget *widget;
+	GtkStyleContext *style;
+	PangoFontDescription *desc;
+	double dpi;
+
+	widget = uiprivControlWidget(c);
+	style = gtk_widget_get_style_context(widget);
+	gtk_style_context_get(style, gtk_style_context_get_state(style),
+		"font", &desc, NULL);
+	uiprivFontDescriptorFromPangoFontDescription(desc, font);
+	// GTK+ resolves CSS sizes to pixels
+	if (pango_font_description_get_size_is_absolute(desc)) {
+		dpi = gdk_screen_get_resolution(gtk_widget_get_screen(widget));
+		if (dpi <= 0)
+			dpi = 96;
+		font->Size = font->Size * 72 / dpi;
+	}
+	// desc is transfer-full and thus is a copy
+	pango_font_description_free(desc);
+}
+
+void uiControlSetTextColor(uiControl *c, double r, double g, double b, double a)
+{
+	struct controlStyle *s;
+
+	s = controlStyle(c);
+	g_free(s->color);
+	s->color = cssColor("color", r, g, b, a);
+	updateStyle(s);
+}
+
+void uiControlResetTextColor(uiControl *c)
+{
+	struct controlStyle *s;
+
+	s = controlStyle(c);
+	g_free(s->color);
+	s->color = NULL;
+	updateStyle(s);
+}
+
+void uiControlSetBackgroundColor(uiControl *c, double r, double g, double b, double a)
+{
+	struct controlStyle *s;
+	char *color;
+
+	s = controlStyle(c);
+	g_free(s->background);
+	color = cssColor("background-color", r, g, b, a);
+	// themes often draw backgrounds as images, which would hide the color
+	s->background = g_strconcat(color, "\tbackground-image: none;\n", NULL);
+	g_free(color);
+	updateStyle(s);
+}
+
+void uiControlResetBackgroundColor(uiControl *c)
+{
+	struct controlStyle *s;
+
+	s = controlStyle(c);
+	g_free(s->background);
+	s->background = NULL;
+	updateStyle(s);
+}
@@ -19,6 +19,10 @@
 #define uiprivGTKXPadding 12
 #define uiprivGTKYPadding 6
 
+// area.c
+extern guint uiprivExtKeyToKeyval(uiExtKey extKey);
+extern GdkModifierType uiprivToGdkModifiers(uiModifiers m);
+
 // menu.c
 extern GtkWidget *uiprivMakeMenubar(uiWindow *);
 extern void uiprivFreeMenubar(GtkWidget *);
@@ -43,12 +47,18 @@ extern void uiprivChildSetFlag(uiprivChild *c, int flag);
 extern GtkWidget *uiprivChildBox(uiprivChild *c);
 extern void uiprivChildSetMargined(uiprivChild *c, int margined);
 
+// control.c
+extern void uiprivSetWidgetControl(uiControl *c);
+extern uiControl *uiprivWidgetControl(GtkWidget *widget);
+extern GtkWidget *uiprivControlWidget(uiControl *c);
+
 // draw.c
 extern uiDrawContext *uiprivNewContext(cairo_t *cr, GtkStyleContext *style);
 extern void uiprivFreeContext(uiDrawContext *);
 
 // image.c
 extern cairo_surface_t *uiprivImageAppropriateSurface(uiImage *i, GtkWidget *w);
+extern GList *uiprivImagePixbufs(uiImage *i);
 
 // cellrendererbutton.c
 extern GtkCellRenderer *uiprivNewCellRendererButton(void);
@@ -31,6 +31,12 @@ struct uiWindow {
 	gboolean fullscreen;
 	void (*onPositionChanged)(uiWindow *, void *);
 	void *onPositionChangedData;
+	void (*onDestroying)(uiWindow *, void *);
+	void *onDestroyingData;
+	void (*onStateChanged)(uiWindow *, void *);
+	void *onStateChangedData;
+	GdkWindowState state;
+	gboolean keepAbove;
 	gboolean changingPosition;
 	gboolean changingSize;
 
@@ -38,6 +44,20 @@ struct uiWindow {
 	gint cachedPosY;
 	gint cachedWidth;
 	gint cachedHeight;
+
+	GdkGeometry geometry;
+	GdkWindowHints hints;
+
+	GtkAccelGroup *shortcuts;
+};
+
+struct shortcut {
+	uiWindow *w;
+	int modifiers;
+	uint32_t key;
+	int extKey;
+	void (*f)(uiWindow *, int, uint32_t, int, void *);
+	void *data;
 };
 
 static gboolean onClosing(GtkWidget *win, GdkEvent *e, gpointer data)
@@ -51,6 +71,22 @@ static gboolean onClosing(GtkWidget *win, GdkEvent *e, gpointer data)
 	return TRUE;
 }
 
+#define stateMask (GDK_WINDOW_STATE_ICONIFIED | GDK_WINDOW_STATE_MAXIMIZED | GDK_WINDOW_STATE_FULLSCREEN | GDK_WINDOW_STATE_ABOVE)
+
+static gboolean onWindowState(GtkWidget *win, GdkEventWindowState *e, gpointer data)
+{
+	uiWindow *w = uiWindow(data);
+
+	w->state = e->new_window_state;
+	w->fullscreen = (w->state & GDK_WINDOW_STATE_FULLSCREEN) != 0;
+	// the window manager may change this on its own, e.g. through the window menu
+	if ((e->changed_mask & GDK_WINDOW_STATE_ABOVE) != 0)
+		w->keepAbove = (w->state & GDK_WINDOW_STATE_ABOVE) != 0;
+	if ((e->changed_mask & stateMask) != 0)
+		(*(w->onStateChanged))(w, w->onStateChangedData);
+	return FALSE;
+}
+
 static void onSizeAllocate(GtkWidget *widget, GdkRectangle *allocation, gpointer data)
 {
 	int width, height;
@@ -121,10 +157,26 @@ static void defaultOnFocusChanged(uiWindow *w, void *data)
 	// do nothing
 }
 
+static int dialogOnClosing(uiWindow *w, void *data)
+{
+	return 1;
+}
+
+static void defaultOnDestroying(uiWindow *w, void *data)
+{
+	// do nothing
+}
+
+static void defaultOnStateChanged(uiWindow *w, void *data)
+{
+	// do nothing
+}
+
 static void uiWindowDestroy(uiControl *c)
 {
 	uiWindow *w = uiWindow(c);
 
+	(*(w->onDestroying))(w, w->onDestroyingData);
 	// first hide ourselves
 	gtk_widget_hide(w->widget);
 	// now destroy the child
@@ -263,6 +315,61 @@ void uiWindowOnClosing(uiWindow *w, int (*f)(uiWindow *, void *), void *data)
 	w->onClosingData = data;
 }
 
+void uiWindowOnDestroying(uiWindow *w, void (*f)(uiWindow *, void *), void *data)
+{
+	w->onDestroying = f;
+	w->onDestroyingData = data;
+}
+
+void uiWindowMinimize(uiWindow *w)
+{
+	gtk_window_iconify(w->window);
+}
+
+int uiWindowMinimized(uiWindow *w)
+{
+	return (w->state & GDK_WINDOW_STATE_ICONIFIED) != 0;
+}
+
+void uiWindowMaximize(uiWindow *w)
+{
+	gtk_window_maximize(w->window);
+}
+
+int uiWindowMaximized(uiWindow *w)
+{
+	return (w->state & GDK_WINDOW_STATE_MAXIMIZED) != 0;
+}
+
+void uiWindowRestore(uiWindow *w)
+{
+	gtk_window_deiconify(w->window);
+	gtk_window_unmaximize(w->window);
+}
+
+int uiWindowKeepAbove(uiWindow *w)
+{
+	return w->keepAbove;
+}
+
+void uiWindowSetKeepAbove(uiWindow *w, int keepAbove)
+{
+	w->keepAbove = keepAbove != 0;
+	gtk_window_set_keep_above(w->window, w->keepAbove);
+}
+
+void uiWindowOnStateChanged(uiWindow *w, void (*f)(uiWindow *, void *), void *data)
+{
+	w->onStateChanged = f;
+	w->onStateChangedData = data;
+}
+
+void uiWindowClose(uiWindow *w)
+{
+	// same as the user closing the window
+	onClosing(w->widget, NULL, w);
+}
+
 int uiWindowFocused(uiWindow *w)
 {
 	return w->focused;
@@ -330,6 +437,157 @@ void uiWindowSetResizeable(uiWindow *w, int resizeable)
 	gtk_window_set_resizable(w->window, resizeable);
 }
 
+void uiWindowSetIcon(uiWindow *w, uiImage *icon)
+{
+	GList *pixbufs = NULL;
+
+	if (icon != NULL)
+		pixbufs = uiprivImagePixbufs(icon);
+	// the window takes its own references
+	gtk_window_set_icon_list(w->window, pixbufs);
+	g_list_free_full(pixbufs, g_object_unref);
+}
+
+// the geometry hints apply to the whole window, so the menubar has to be added to content heights
+static gint menubarHeight(uiWindow *w)
+{
+	gint height;
+
+	if (w->menubar == NULL)
+		return 0;
+	gtk_widget_get_preferred_height(w->menubar, NULL, &height);
+	return height;
+}
+
+static void setGeometryHint(uiWindow *w, GdkWindowHints hint, gint *fieldWidth, gint *fieldHeight, int width, int height)
+{
+	*fieldWidth = width;
+	*fieldHeight = height;
+	if (width > 0 || height > 0)
+		w->hints |= hint;
+	else
+		w->hints &= ~hint;
+	gtk_window_set_geometry_hints(w->window, NULL, &(w->geometry), w->hints);
+}
+
+void uiWindowSetMinContentSize(uiWindow *w, int width, int height)
+{
+	if (height > 0)
+		height += menubarHeight(w);
+	setGeometryHint(w, GDK_HINT_MIN_SIZE, &(w->geometry.min_width), &(w->geometry.min_height), width, height);
+}
+
+void uiWindowSetMaxContentSize(uiWindow *w, int width, int height)
+{
+	// GDK takes both dimensions at once; a missing one must not limit the window
+	if (width <= 0 && height > 0)
+		width = G_MAXSHORT;
+	if (height <= 0 && width > 0)
+		height = G_MAXSHORT;
+	else if (height > 0)
+		height += menubarHeight(w);
+	setGeometryHint(w, GDK_HINT_MAX_SIZE, &(w->geometry.max_width), &(w->geometry.max_height), width, height);
+}
+
+void uiWindowSetAspectRatio(uiWindow *w, double width, double height)
+{
+	if (width > 0 && height > 0) {
+		w->geometry.min_aspect = width / height;
+		w->geometry.max_aspect = width / height;
+		w->hints |= GDK_HINT_ASPECT;
+	} else
+		w->hints &= ~GDK_HINT_ASPECT;
+	gtk_window_set_geometry_hints(w->window, NULL, &(w->geometry), w->hints);
+}
+
+void uiScreenWorkArea(uiWindow *w, int *x, int *y, int *width, int *height)
+{
+	GdkScreen *screen;
+	GdkWindow *window;
+	GdkRectangle r = { 0, 0, 0, 0 };
+	gint monitor;
+
+	window = NULL;
+	if (w != NULL) {
+		screen = gtk_window_get_screen(w->window);
+		window = gtk_widget_get_window(w->widget);
+	} else
+		screen = gdk_screen_get_default();
+	if (screen == NULL) {
+		*x = 0;
+		*y = 0;
+		*width = 0;
+		*height = 0;
+		return;
+	}
+	// windows without a GdkWindow are not on any monitor yet
+	if (window != NULL)
+		monitor = gdk_screen_get_monitor_at_window(screen, window);
+	else
+		monitor = gdk_screen_get_primary_monitor(screen);
+	gdk_screen_get_monitor_workarea(screen, monitor, &r);
+	*x = r.x;
+	*y = r.y;
+	*width = r.width;
+	*height = r.height;
+}
+
+static gboolean onShortcut(GtkAccelGroup *accel, GObject *acceleratable, guint keyval, GdkModifierType mods, gpointer data)
+{
+	struct shortcut *s = (struct shortcut *) data;
+
+	(*(s->f))(s->w, s->modifiers, s->key, s->extKey, s->data);
+	return TRUE;
+}
+
+static void freeShortcut(gpointer data, GClosure *closure)
+{
+	uiprivFree(data);
+}
+
+static guint shortcutKeyval(uint32_t key, int extKey)
+{
+	if (extKey != 0)
+		return uiprivExtKeyToKeyval(extKey);
+	return gdk_keyval_to_lower(gdk_unicode_to_keyval(key));
+}
+
+void uiWindowAddShortcut(uiWindow *w, int modifiers, uint32_t key, int extKey,
+	void (*f)(uiWindow *, int, uint32_t, int, void *), void *data)
+{
+	struct shortcut *s;
+	GClosure *closure;
+	guint keyval;
+
+	keyval = shortcutKeyval(key, extKey);
+	if (keyval == 0)
+		uiprivUserBug("You cannot add a shortcut without a key.");
+	// a separate accel group keeps shortcuts working in windows without a menubar
+	if (w->shortcuts == NULL) {
+		w->shortcuts = gtk_accel_group_new();
+		gtk_window_add_accel_group(w->window, w->shortcuts);
+		g_object_unref(w->shortcuts);
+	}
+	uiWindowRemoveShortcut(w, modifiers, key, extKey);
+
+	s = uiprivNew(struct shortcut);
+	s->w = w;
+	s->modifiers = modifiers;
+	s->key = key;
+	s->extKey = extKey;
+	s->f = f;
+	s->data = data;
+	closure = g_cclosure_new(G_CALLBACK(onShortcut), s, freeShortcut);
+	gtk_accel_group_connect(w->shortcuts, keyval, uiprivToGdkModifiers(modifiers), 0, closure);
+}
+
+void uiWindowRemoveShortcut(uiWindow *w, int modifiers, uint32_t key, int extKey)
+{
+	if (w->shortcuts == NULL)
+		return;
+	gtk_accel_group_disconnect_key(w->shortcuts, shortcutKeyval(key, extKey), uiprivToGdkModifiers(modifiers));
+}
+
 uiWindow *uiNewWindow(const char *title, int width, int height, int hasMenubar)
 {
 	uiWindow *w;
@@ -382,11 +640,14 @@ uiWindow *uiNewWindow(const char *title, int width, int height, int hasMenubar)
 	g_signal_connect(w->widget, "focus-in-event", G_CALLBACK(onGetFocus), w);
 	g_signal_connect(w->widget, "focus-out-event", G_CALLBACK(onLoseFocus), w);
 	g_signal_connect(w->widget, "configure-event", G_CALLBACK(onConfigure), w);
+	g_signal_connect(w->widget, "window-state-event", G_CALLBACK(onWindowState), w);
 
 	uiWindowOnClosing(w, defaultOnClosing, NULL);
 	uiWindowOnContentSizeChanged(w, defaultOnPositionContentSizeChanged, NULL);
 	uiWindowOnFocusChanged(w, defaultOnFocusChanged, NULL);
 	uiWindowOnPositionChanged(w, defaultOnPositionContentSizeChanged, NULL);
+	uiWindowOnDestroying(w, defaultOnDestroying, NULL);
+	uiWindowOnStateChanged(w, defaultOnStateChanged, NULL);
 
 	// normally it's SetParent() that does this, but we can't call SetParent() on a uiWindow
 	// TODO we really need to clean this up, especially since see uiWindowDestroy() above
@@ -394,3 +655,19 @@ uiWindow *uiNewWindow(const char *title, int width, int height, int hasMenubar)
 
 	return w;
 }
+
+uiWindow *uiNewDialogWindow(uiWindow *parent, const char *title, int width, int height)
+{
+	uiWindow *w;
+
+	w = uiNewWindow(title, width, height, 0);
+	gtk_window_set_type_hint(w->window, GDK_WINDOW_TYPE_HINT_DIALOG);
+	gtk_window_set_skip_taskbar_hint(w->window, TRUE);
+	gtk_window_set_modal(w->window, TRUE);
+	// keeps the dialog above the parent and centers it there
+	gtk_window_set_transient_for(w->window, parent->window);
+	gtk_window_set_position(w->window, GTK_WIN_POS_CENTER_ON_PARENT);
+	// unlike regular windows, dialogs close when the user asks to
+	uiWindowOnClosing(w, dialogOnClosing, NULL);
+	return w;
+}
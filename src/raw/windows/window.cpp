@@ -28,6 +28,8 @@ struct uiWindow {
 	void (*onPositionChanged)(uiWindow *, void *);
 	void *onPositionChangedData;
 	BOOL changingPosition;
+	void (*onDestroying)(uiWindow *, void *);
+	void *onDestroyingData;
 };
 
 // from https://msdn.microsoft.com/en-us/library/windows/desktop/dn742486.aspx#sizingandspacing
@@ -172,12 +174,19 @@ static void defaultOnFocusChanged(uiWindow *w, void *data)
 	// do nothing
 }
 
+static void defaultOnDestroying(uiWindow *w, void *data)
+{
+	// do nothing
+}
+
 static std::map<uiWindow *, bool> windows;
 
 static void uiWindowDestroy(uiControl *c)
 {
 	uiWindow *w = uiWindow(c);
 
+	// let the callback see the window before anything is torn down
+	(*(w->onDestroying))(w, w->onDestroyingData);
 	// first hide ourselves
 	ShowWindow(w->hwnd, SW_HIDE);
 	// now destroy the child
@@ -426,6 +435,12 @@ void uiWindowOnClosing(uiWindow *w, int (*f)(uiWindow *, void *), void *data)
 	w->onClosingData = data;
 }
 
+void uiWindowOnDestroying(uiWindow *w, void (*f)(uiWindow *, void *), void *data)
+{
+	w->onDestroying = f;
+	w->onDestroyingData = data;
+}
+
 void uiWindowOnFocusChanged(uiWindow *w, void (*f)(uiWindow *, void *), void *data)
 {
 	w->onFocusChanged = f;
@@ -563,6 +578,7 @@ uiWindow *uiNewWindow(const char *title, int width, int height, int hasMenubar)
 	uiWindowOnContentSizeChanged(w, defaultOnPositionContentSizeChanged, NULL);
 	uiWindowOnFocusChanged(w, defaultOnFocusChanged, NULL);
 	uiWindowOnPositionChanged(w, defaultOnPositionContentSizeChanged, NULL);
+	uiWindowOnDestroying(w, defaultOnDestroying, NULL);
 
 	windows[w] = true;
 	return w;
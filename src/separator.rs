@@ -14,6 +14,8 @@ impl AsRef<Self> for Separator {
 }
 
 impl Control for Separator {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x53657061);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
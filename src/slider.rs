@@ -1,3 +1,16 @@
+#[cfg(target_os = "linux")]
+use {
+    crate::raw::{
+        uiNewSliderDouble, uiSliderAddTick, uiSliderClearTicks, uiSliderPage, uiSliderPrecision,
+        uiSliderSetIncrements, uiSliderSetPrecision, uiSliderSetRangeDouble,
+        uiSliderSetValueDouble, uiSliderSetVertical, uiSliderStep, uiSliderValueDouble,
+        uiSliderVertical,
+    },
+    std::{
+        ffi::{CString, NulError},
+        ptr::null,
+    },
+};
 use {
     crate::{
         Control, define_callback_function,
@@ -24,6 +37,8 @@ impl AsRef<Self> for Slider {
 }
 
 impl Control for Slider {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x536C6964);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
@@ -156,4 +171,159 @@ impl Slider {
         let ptr = unsafe { uiNewSlider(min, max) };
         Self { _inner: ptr }.into()
     }
+
+    /// Creates a new slider with fractional values.
+    ///
+    /// The initial slider value equals the minimum value.
+    /// `min` and `max` are swapped if `min>max`.
+    ///
+    /// # arguments
+    /// * `min`: Minimum value.
+    /// * `max`: Maximum value.
+    /// * `step`: Step increment of the arrow keys, greater than `0`.
+    ///   The precision is derived from it, e.g. `2` decimals for `0.01`.
+    ///
+    /// # returns
+    /// A new uiSlider instance.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn new_f64(min: f64, max: f64, step: f64) -> Self {
+        let ptr = unsafe { uiNewSliderDouble(min, max, step) };
+        Self { _inner: ptr }
+    }
+
+    /// Returns the slider value as fractional number.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn value_f64(&self) -> f64 {
+        unsafe { uiSliderValueDouble(self._inner) }
+    }
+
+    /// Sets the slider value from a fractional number.
+    ///
+    /// # arguments
+    /// * `value`: Value to set.
+    ///
+    /// # note
+    /// * Setting a value out of range will clamp to the nearest value in range.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_value_f64(&self, value: f64) {
+        unsafe { uiSliderSetValueDouble(self._inner, value) }
+    }
+
+    /// Sets the slider range from fractional numbers.
+    ///
+    /// # arguments
+    /// * `min`: Minimum value.
+    /// * `max`: Maximum value.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_range_f64(&self, min: f64, max: f64) {
+        unsafe { uiSliderSetRangeDouble(self._inner, min, max) }
+    }
+
+    /// Returns the step increment of the arrow keys.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn step(&self) -> f64 {
+        unsafe { uiSliderStep(self._inner) }
+    }
+
+    /// Returns the page increment of the Page Up and Page Down keys.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn page(&self) -> f64 {
+        unsafe { uiSliderPage(self._inner) }
+    }
+
+    /// Sets the step and page increments of the slider.
+    ///
+    /// # arguments
+    /// * `step`: Step increment of the arrow keys.
+    /// * `page`: Page increment of the Page Up and Page Down keys.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_increments(&self, step: f64, page: f64) {
+        unsafe { uiSliderSetIncrements(self._inner, step, page) }
+    }
+
+    /// Returns the number of decimals the slider rounds its value to.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn precision(&self) -> i32 {
+        unsafe { uiSliderPrecision(self._inner) }
+    }
+
+    /// Sets the number of decimals the slider rounds its value to.
+    /// The tool tip shows the value with as many decimals.
+    ///
+    /// # arguments
+    /// * `digits`: Number of decimals.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_precision(&self, digits: i32) {
+        unsafe { uiSliderSetPrecision(self._inner, digits) }
+    }
+
+    /// Returns whether the slider is vertical.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn vertical(&self) -> bool {
+        unsafe { uiSliderVertical(self._inner) != 0 }
+    }
+
+    /// Sets whether the slider is vertical. Vertical sliders have their maximum at the top.
+    ///
+    /// # arguments
+    /// * `vertical`: `true` for a vertical slider, `false` for a horizontal one.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_vertical(&self, vertical: bool) {
+        unsafe { uiSliderSetVertical(self._inner, vertical as _) }
+    }
+
+    /// Adds a tick mark to the slider. Dragging the slider snaps to nearby marks.
+    ///
+    /// # arguments
+    /// * `value`: Value to mark.
+    /// * `label`: Text shown next to the mark, `None` for none.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn add_tick(&self, value: f64, label: Option<&str>) -> Result<(), NulError> {
+        let label = label.map(CString::new).transpose()?;
+        let ptr = label.as_ref().map_or(null(), |l| l.as_ptr());
+        Ok(unsafe { uiSliderAddTick(self._inner, value, ptr) })
+    }
+
+    /// Removes all tick marks from the slider.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn clear_ticks(&self) {
+        unsafe { uiSliderClearTicks(self._inner) }
+    }
 }
@@ -1,3 +1,9 @@
+#[cfg(target_os = "linux")]
+use crate::raw::{
+    uiNewSpinboxDouble, uiSpinboxPage, uiSpinboxPrecision, uiSpinboxSetIncrements,
+    uiSpinboxSetPrecision, uiSpinboxSetRange, uiSpinboxSetRangeDouble, uiSpinboxSetValueDouble,
+    uiSpinboxStep, uiSpinboxValueDouble,
+};
 use {
     crate::{
         Control, define_callback_function,
@@ -23,6 +29,8 @@ impl AsRef<Self> for Spinbox {
 }
 
 impl Control for Spinbox {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x5370696E);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
@@ -97,4 +105,129 @@ impl Spinbox {
         let ptr = unsafe { uiNewSpinbox(min, max) };
         Self { _inner: ptr }.into()
     }
+
+    /// Creates a new spinbox with fractional values.
+    ///
+    /// The initial spinbox value equals the minimum value.
+    /// `min` and `max` are swapped if `min>max`.
+    ///
+    /// # arguments
+    /// * `min`: Minimum value.
+    /// * `max`: Maximum value.
+    /// * `step`: Step increment of the arrows, greater than `0`.
+    ///   The precision is derived from it, e.g. `2` decimals for `0.01`.
+    ///
+    /// # returns
+    /// A new uiSpinbox instance.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn new_f64(min: f64, max: f64, step: f64) -> Self {
+        let ptr = unsafe { uiNewSpinboxDouble(min, max, step) };
+        Self { _inner: ptr }
+    }
+
+    /// Returns the spinbox value as fractional number.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn value_f64(&self) -> f64 {
+        unsafe { uiSpinboxValueDouble(self._inner) }
+    }
+
+    /// Sets the spinbox value from a fractional number.
+    ///
+    /// # arguments
+    /// * `value`: Value to set.
+    ///
+    /// # note
+    /// * Setting a value out of range will clamp to the nearest value in range.
+    /// * The value is rounded to the precision of the spinbox.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_value_f64(&self, value: f64) {
+        unsafe { uiSpinboxSetValueDouble(self._inner, value) }
+    }
+
+    /// Sets the spinbox range.
+    ///
+    /// # arguments
+    /// * `min`: Minimum value.
+    /// * `max`: Maximum value.
+    ///
+    /// # note
+    /// * The value is clamped to the new range without triggering `on_changed()`.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_range(&self, min: i32, max: i32) {
+        unsafe { uiSpinboxSetRange(self._inner, min, max) }
+    }
+
+    /// Sets the spinbox range from fractional numbers.
+    ///
+    /// # arguments
+    /// * `min`: Minimum value.
+    /// * `max`: Maximum value.
+    ///
+    /// # note
+    /// * The value is clamped to the new range without triggering `on_changed()`.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_range_f64(&self, min: f64, max: f64) {
+        unsafe { uiSpinboxSetRangeDouble(self._inner, min, max) }
+    }
+
+    /// Returns the step increment of the arrows and arrow keys.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn step(&self) -> f64 {
+        unsafe { uiSpinboxStep(self._inner) }
+    }
+
+    /// Returns the page increment of the Page Up and Page Down keys.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn page(&self) -> f64 {
+        unsafe { uiSpinboxPage(self._inner) }
+    }
+
+    /// Sets the step and page increments of the spinbox.
+    ///
+    /// # arguments
+    /// * `step`: Step increment of the arrows and arrow keys.
+    /// * `page`: Page increment of the Page Up and Page Down keys.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_increments(&self, step: f64, page: f64) {
+        unsafe { uiSpinboxSetIncrements(self._inner, step, page) }
+    }
+
+    /// Returns the number of decimals the spinbox shows.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn precision(&self) -> i32 {
+        unsafe { uiSpinboxPrecision(self._inner) }
+    }
+
+    /// Sets the number of decimals the spinbox shows.
+    ///
+    /// # arguments
+    /// * `digits`: Number of decimals, at most `20`.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_precision(&self, digits: i32) {
+        unsafe { uiSpinboxSetPrecision(self._inner, digits) }
+    }
 }
@@ -0,0 +1,77 @@
+use {
+    crate::{
+        Color, Control, Entry, FontDescriptor, Label, MultiLineEntry,
+        raw::{
+            uiControlFont, uiControlResetBackgroundColor, uiControlResetTextColor,
+            uiControlSetBackgroundColor, uiControlSetFont, uiControlSetTextColor, uiFontDescriptor,
+            uiFreeFontDescriptor,
+        },
+    },
+    std::{ffi::NulError, mem::zeroed, ptr::null},
+};
+
+/// Font and color styling of controls displaying text:
+/// `Label`, `Entry` and `MultiLineEntry`.
+/// Only available on Linux for now.
+pub trait TextStyle: Control {
+    /// Returns the font the control's text is drawn with.
+    ///
+    /// # returns
+    /// * The font set with `set_font()`, the default font otherwise.
+    ///
+    /// # note
+    /// * Sizes are rounded by the toolkit, they may differ slightly from the set size.
+    fn font(&self) -> FontDescriptor {
+        let mut desc: uiFontDescriptor = unsafe { zeroed() };
+        unsafe { uiControlFont(self.as_ptr_mut(), &mut desc) };
+        let font = unsafe { FontDescriptor::from_raw(&desc) };
+        unsafe { uiFreeFontDescriptor(&mut desc) };
+        font
+    }
+
+    /// Sets the font of the control's text.
+    ///
+    /// # arguments
+    /// * `font`: The font, e.g. `FontDescriptor::monospace(10.)`, `None` for the default font.
+    fn set_font(&self, font: Option<&FontDescriptor>) -> Result<(), NulError> {
+        match font {
+            None => Ok(unsafe { uiControlSetFont(self.as_ptr_mut(), null()) }),
+            Some(font) => {
+                font.with_raw(|desc| unsafe { uiControlSetFont(self.as_ptr_mut(), desc) })
+            }
+        }
+    }
+
+    /// Sets the color of the control's text.
+    ///
+    /// # arguments
+    /// * `color`: Text color, `None` for the default color.
+    fn set_text_color(&self, color: Option<Color>) {
+        match color {
+            None => unsafe { uiControlResetTextColor(self.as_ptr_mut()) },
+            Some(c) => unsafe { uiControlSetTextColor(self.as_ptr_mut(), c.r, c.g, c.b, c.a) },
+        }
+    }
+
+    /// Sets the background color of the control.
+    ///
+    /// # arguments
+    /// * `color`: Background color, `None` for the default color.
+    ///
+    /// # note
+    /// * Labels have no background of their own and may not show the color on all platforms.
+    fn set_background_color(&self, color: Option<Color>) {
+        match color {
+            None => unsafe { uiControlResetBackgroundColor(self.as_ptr_mut()) },
+            Some(c) => unsafe {
+                uiControlSetBackgroundColor(self.as_ptr_mut(), c.r, c.g, c.b, c.a)
+            },
+        }
+    }
+}
+
+impl TextStyle for Label {}
+
+impl TextStyle for Entry {}
+
+impl TextStyle for MultiLineEntry {}
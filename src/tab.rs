@@ -28,6 +28,8 @@ impl AsRef<Self> for Tab {
 }
 
 impl Control for Tab {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x54616273);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
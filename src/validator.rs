@@ -0,0 +1,456 @@
+use {
+    crate::{
+        Button, Control, EditableCombobox, Entry, MultiLineEntry, error::UiError,
+        handle::ControlHandle,
+    },
+    log::error,
+    std::sync::{Arc, Mutex, MutexGuard, Weak},
+};
+
+/// Checks and optionally reformats the text of an input control.
+///
+/// Closures of type `Fn(&str) -> bool` are validators as well.
+/// Only available on Linux for now.
+pub trait Validator: Send {
+    /// Returns whether `text` is valid.
+    fn validate(&self, text: &str) -> bool;
+
+    /// Returns the reformatted text, `None` to keep the text as typed.
+    /// The reformatted text is validated instead of the typed one.
+    fn format(&self, _text: &str) -> Option<String> {
+        None
+    }
+}
+
+impl<F> Validator for F
+where
+    F: Fn(&str) -> bool + Send,
+{
+    fn validate(&self, text: &str) -> bool {
+        self(text)
+    }
+}
+
+/// Accepts any text that is not empty or whitespace only.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NonEmpty;
+
+impl Validator for NonEmpty {
+    fn validate(&self, text: &str) -> bool {
+        !text.trim().is_empty()
+    }
+}
+
+/// Accepts e-mail addresses of the form `local@domain.tld`.
+///
+/// Only the overall shape is checked, not whether the address exists.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Email;
+
+impl Validator for Email {
+    fn validate(&self, text: &str) -> bool {
+        let Some((local, domain)) = text.split_once('@') else {
+            return false;
+        };
+        !local.is_empty()
+            && !text.chars().any(char::is_whitespace)
+            && !domain.contains('@')
+            && domain.contains('.')
+            && domain.split('.').all(|label| !label.is_empty())
+    }
+}
+
+/// Accepts numbers in an inclusive range.
+#[derive(Clone, Copy, Debug)]
+pub struct NumberRange {
+    min: f64,
+    max: f64,
+    separator: Option<char>,
+}
+
+impl NumberRange {
+    /// Creates a new range validator.
+    ///
+    /// # arguments
+    /// * `min`: Smallest accepted number.
+    /// * `max`: Largest accepted number.
+    pub fn new(min: f64, max: f64) -> Self {
+        Self {
+            min,
+            max,
+            separator: None,
+        }
+    }
+
+    /// Groups the digits before the decimal point in threes, e.g. `1,234,567.5`.
+    ///
+    /// # arguments
+    /// * `separator`: Character put between the groups, ignored when validating.
+    pub fn thousands_separator(mut self, separator: char) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    fn parse(&self, text: &str) -> Option<f64> {
+        let text = text.trim();
+        let number = match self.separator {
+            Some(s) => text.replace(s, "").parse(),
+            None => text.parse(),
+        };
+        number.ok().filter(|n: &f64| n.is_finite())
+    }
+}
+
+impl Validator for NumberRange {
+    fn validate(&self, text: &str) -> bool {
+        self.parse(text)
+            .is_some_and(|n| (self.min..=self.max).contains(&n))
+    }
+
+    fn format(&self, text: &str) -> Option<String> {
+        let separator = self.separator?;
+        self.parse(text)?;
+
+        let text = text.trim().replace(separator, "");
+        let (sign, text) = match text.strip_prefix(['-', '+']) {
+            Some(rest) => (&text[..1], rest),
+            None => ("", text.as_str()),
+        };
+        let (integer, fraction) = match text.find('.') {
+            Some(i) => text.split_at(i),
+            None => (text, ""),
+        };
+
+        let mut grouped = String::from(sign);
+        for (i, c) in integer.chars().enumerate() {
+            if i > 0 && (integer.len() - i) % 3 == 0 {
+                grouped.push(separator);
+            }
+            grouped.push(c);
+        }
+        grouped.push_str(fraction);
+        Some(grouped)
+    }
+}
+
+/// Accepts text matching a regular expression as a whole.
+#[cfg(feature = "regex")]
+#[derive(Clone, Debug)]
+pub struct Pattern(regex::Regex);
+
+#[cfg(feature = "regex")]
+impl Pattern {
+    /// Creates a new pattern validator.
+    ///
+    /// # arguments
+    /// * `pattern`: Regular expression the whole text has to match.
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self(regex::Regex::new(&format!("^(?:{})$", pattern))?))
+    }
+}
+
+#[cfg(feature = "regex")]
+impl Validator for Pattern {
+    fn validate(&self, text: &str) -> bool {
+        self.0.is_match(text)
+    }
+}
+
+struct Field {
+    validator: Box<dyn Validator>,
+    valid: bool,
+    form: Weak<Form>,
+}
+
+impl Field {
+    /// Validates `text` and returns the reformatted text, if it changed.
+    fn update(&mut self, text: &str) -> Option<String> {
+        let formatted = self.validator.format(text).filter(|f| f != text);
+        self.valid = self
+            .validator
+            .validate(formatted.as_deref().unwrap_or(text));
+        formatted
+    }
+}
+
+/// The validation state of an input control, returned by `Validate::set_validator()`.
+///
+/// Cloned values refer to the same state. Once the control was destroyed, the state of its
+/// last change is kept.
+/// Only available on Linux for now.
+#[derive(Clone)]
+pub struct Validation {
+    control: ControlHandle,
+    // Typed accessors of the control, `None` once it was destroyed.
+    text: fn(&ControlHandle) -> Option<Result<String, UiError>>,
+    set_text: fn(&ControlHandle, &str) -> Result<(), UiError>,
+    focus: fn(&ControlHandle),
+    field: Arc<Mutex<Field>>,
+}
+
+impl Validation {
+    fn lock(&self) -> Result<MutexGuard<'_, Field>, UiError> {
+        Ok(self.field.lock()?)
+    }
+
+    /// Returns whether the control's text was valid after its last change.
+    pub fn is_valid(&self) -> Result<bool, UiError> {
+        Ok(self.lock()?.valid)
+    }
+
+    /// Validates the current text of the control again.
+    /// Needed after the text was set programmatically, which does not trigger validation.
+    ///
+    /// # returns
+    /// * `true` if the text is valid, `false` otherwise.
+    pub fn validate(&self) -> Result<bool, UiError> {
+        if let Some(text) = (self.text)(&self.control) {
+            self.changed(&text?)?;
+        }
+        self.is_valid()
+    }
+
+    fn changed(&self, text: &str) -> Result<(), UiError> {
+        let mut field = self.lock()?;
+        let formatted = field.update(text);
+        let form = field.form.upgrade();
+        // The form locks every field, including this one.
+        drop(field);
+
+        if let Some(formatted) = formatted {
+            (self.set_text)(&self.control, &formatted)?;
+        }
+        if let Some(form) = form {
+            form.update()?;
+        }
+        Ok(())
+    }
+}
+
+mod private {
+    use crate::{Control, error::UiError};
+
+    pub trait TextInput: Control + Sized {
+        fn input_text(&self) -> Result<String, UiError>;
+
+        fn set_input_text(&self, text: &str) -> Result<(), UiError>;
+
+        fn on_input_changed<F>(&self, f: F) -> Result<(), UiError>
+        where
+            F: FnMut(Self) + Send + 'static;
+
+        /// Returns the character index of the text cursor, `None` if unknown.
+        fn input_cursor(&self) -> Option<i32> {
+            None
+        }
+
+        fn set_input_cursor(&self, _pos: i32) {}
+
+        /// Replaces the text by its reformatted version.
+        /// The cursor stays behind the same number of letters and digits, as formatting
+        /// usually adds or removes separators only.
+        fn set_formatted_text(&self, text: &str) -> Result<(), UiError> {
+            let Some(pos) = self.input_cursor() else {
+                return self.set_input_text(text);
+            };
+            let typed = self
+                .input_text()?
+                .chars()
+                .take(pos.max(0) as _)
+                .filter(|c| c.is_alphanumeric())
+                .count();
+            self.set_input_text(text)?;
+
+            let mut seen = 0;
+            let pos = text
+                .chars()
+                .take_while(|c| {
+                    let before = seen < typed;
+                    seen += c.is_alphanumeric() as usize;
+                    before
+                })
+                .count();
+            self.set_input_cursor(pos as _);
+            Ok(())
+        }
+    }
+}
+
+/// Input controls that can be validated: `Entry`, `EditableCombobox` and `MultiLineEntry`.
+/// Only available on Linux for now.
+pub trait Validate: private::TextInput {
+    /// Validates the control's text each time the user changes it.
+    ///
+    /// # arguments
+    /// * `validator`: Checks and optionally reformats the text.
+    ///
+    /// # returns
+    /// * The validation state of the control.
+    ///
+    /// # note
+    /// * This replaces the callback registered via `on_changed()`.
+    /// * Setting the text programmatically does not trigger validation, use `Validation::validate()`.
+    fn set_validator<V>(&self, validator: V) -> Result<Validation, UiError>
+    where
+        V: Validator + 'static,
+    {
+        let validation = Validation {
+            control: ControlHandle::new(self),
+            text: |c| c.get::<Self>().map(|c| c.input_text()),
+            set_text: |c, text| {
+                c.get::<Self>()
+                    .map_or(Ok(()), |c| c.set_formatted_text(text))
+            },
+            focus: |c| {
+                if let Some(c) = c.get::<Self>() {
+                    c.focus()
+                }
+            },
+            field: Arc::new(Mutex::new(Field {
+                validator: Box::new(validator),
+                valid: false,
+                form: Weak::new(),
+            })),
+        };
+        validation.validate()?;
+
+        let v = validation.clone();
+        self.on_input_changed(move |c| {
+            if let Err(e) = c.input_text().and_then(|text| v.changed(&text)) {
+                error!("An error was occurred in validation: {}", e);
+            }
+        })?;
+        Ok(validation)
+    }
+}
+
+macro_rules! impl_validate {
+    ($($control: ty $({ $($cursor: item)* })?),*) => {
+        $(
+            impl private::TextInput for $control {
+                fn input_text(&self) -> Result<String, UiError> {
+                    Ok(self.text()?)
+                }
+
+                fn set_input_text(&self, text: &str) -> Result<(), UiError> {
+                    Ok(self.set_text(text)?)
+                }
+
+                fn on_input_changed<F>(&self, mut f: F) -> Result<(), UiError>
+                where
+                    F: FnMut(Self) + Send + 'static,
+                {
+                    self.on_changed(move |c, _| f(c), &mut ())
+                }
+
+                $($($cursor)*)?
+            }
+
+            impl Validate for $control {}
+        )*
+    };
+}
+
+impl_validate!(
+    Entry {
+        fn input_cursor(&self) -> Option<i32> {
+            Some(self.cursor_position())
+        }
+
+        fn set_input_cursor(&self, pos: i32) {
+            self.select_range(pos, pos)
+        }
+    },
+    EditableCombobox,
+    MultiLineEntry {
+        fn input_cursor(&self) -> Option<i32> {
+            Some(self.cursor_position())
+        }
+
+        fn set_input_cursor(&self, pos: i32) {
+            self.set_cursor_position(pos)
+        }
+    }
+);
+
+struct Form {
+    fields: Mutex<Vec<Validation>>,
+    submit: Option<ControlHandle>,
+}
+
+impl Form {
+    /// Enables the submit button if every field is valid and disables it otherwise.
+    fn update(&self) -> Result<bool, UiError> {
+        let mut valid = true;
+        for field in self.fields.lock()?.iter() {
+            valid &= field.is_valid()?;
+        }
+        if let Some(submit) = self.submit.as_ref().and_then(ControlHandle::get::<Button>) {
+            match valid {
+                true => submit.enable(),
+                false => submit.disable(),
+            }
+        }
+        Ok(valid)
+    }
+}
+
+/// Aggregates the validation of several input controls.
+///
+/// The submit button is only enabled while every field is valid.
+/// Only available on Linux for now.
+pub struct FormValidator {
+    form: Arc<Form>,
+}
+
+impl FormValidator {
+    /// Creates a new form validator.
+    ///
+    /// # arguments
+    /// * `submit`: Button enabled only while every field is valid, `None` for no button.
+    pub fn new(submit: Option<&Button>) -> Self {
+        Self {
+            form: Arc::new(Form {
+                fields: Mutex::new(Vec::new()),
+                submit: submit.map(ControlHandle::new),
+            }),
+        }
+    }
+
+    /// Adds a field to the form.
+    ///
+    /// # arguments
+    /// * `field`: The validation state returned by `Validate::set_validator()`.
+    ///
+    /// # note
+    /// * A field can only belong to one form at a time.
+    pub fn field(&self, field: &Validation) -> Result<&Self, UiError> {
+        field.lock()?.form = Arc::downgrade(&self.form);
+        self.form.fields.lock()?.push(field.clone());
+        self.form.update()?;
+        Ok(self)
+    }
+
+    /// Returns whether every field was valid after its last change.
+    pub fn is_valid(&self) -> Result<bool, UiError> {
+        self.form.update()
+    }
+
+    /// Validates every field again and focuses the first invalid one.
+    ///
+    /// # returns
+    /// * `true` if every field is valid, `false` otherwise.
+    pub fn validate(&self) -> Result<bool, UiError> {
+        let fields = self.form.fields.lock()?.clone();
+        let mut first_invalid = None;
+        for field in &fields {
+            if !field.validate()? && first_invalid.is_none() {
+                first_invalid = Some(field);
+            }
+        }
+        if let Some(field) = first_invalid {
+            (field.focus)(&field.control)
+        }
+        Ok(first_invalid.is_none())
+    }
+}
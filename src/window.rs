@@ -1,29 +1,211 @@
+#[cfg(target_os = "linux")]
+use crate::{
+    Accelerator, Image,
+    raw::{
+        uiNewDialogWindow, uiScreenWorkArea, uiWindowAddShortcut, uiWindowClose,
+        uiWindowFocusedControl, uiWindowKeepAbove, uiWindowMaximize, uiWindowMaximized,
+        uiWindowMinimize, uiWindowMinimized, uiWindowOnStateChanged, uiWindowRemoveShortcut,
+        uiWindowRestore, uiWindowSetAspectRatio, uiWindowSetIcon, uiWindowSetKeepAbove,
+        uiWindowSetMaxContentSize, uiWindowSetMinContentSize,
+    },
+};
 use {
     crate::{
         Control, define_callback_function,
         error::UiError,
+        file_dialog::path_from_c,
         modify_callback,
         raw::{
-            uiControl, uiFreeText, uiMsgBox, uiMsgBoxError, uiNewWindow, uiOpenFile, uiOpenFolder,
-            uiSaveFile, uiWindow, uiWindowBorderless, uiWindowContentSize, uiWindowFocused,
-            uiWindowFullscreen, uiWindowMargined, uiWindowOnClosing, uiWindowOnContentSizeChanged,
-            uiWindowOnFocusChanged, uiWindowOnPositionChanged, uiWindowPosition,
-            uiWindowResizeable, uiWindowSetBorderless, uiWindowSetChild, uiWindowSetContentSize,
-            uiWindowSetFullscreen, uiWindowSetMargined, uiWindowSetPosition, uiWindowSetResizeable,
-            uiWindowSetTitle, uiWindowTitle,
+            uiControl, uiFreeText, uiMainStep, uiMsgBox, uiMsgBoxError, uiNewWindow, uiOpenFile,
+            uiOpenFolder, uiQuit, uiSaveFile, uiWindow, uiWindowBorderless, uiWindowContentSize,
+            uiWindowFocused, uiWindowFullscreen, uiWindowMargined, uiWindowOnClosing,
+            uiWindowOnContentSizeChanged, uiWindowOnDestroying, uiWindowOnFocusChanged,
+            uiWindowOnPositionChanged, uiWindowPosition, uiWindowResizeable, uiWindowSetBorderless,
+            uiWindowSetChild, uiWindowSetContentSize, uiWindowSetFullscreen, uiWindowSetMargined,
+            uiWindowSetPosition, uiWindowSetResizeable, uiWindowSetTitle, uiWindowTitle,
         },
     },
     log::error,
     std::{
+        any::Any,
         collections::HashMap,
-        ffi::{CStr, CString, NulError, c_void},
+        ffi::{CStr, CString, NulError, c_int, c_void},
         mem::transmute,
         path::PathBuf,
+        ptr::null_mut,
         str::Utf8Error,
-        sync::Mutex,
+        sync::{
+            Arc, Mutex,
+            atomic::{AtomicBool, Ordering},
+        },
     },
 };
 
+static WINDOW_CREATED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(target_os = "linux")]
+/// Returns whether a window was created, after which no more menus may be created.
+pub(crate) fn window_created() -> bool {
+    WINDOW_CREATED.load(Ordering::Relaxed)
+}
+
+/// State of a modal loop waiting for its dialog to close.
+#[derive(Default)]
+struct Modal {
+    closed: bool,
+    result: Option<Box<dyn Any + Send>>,
+}
+
+static MODALS: Mutex<Option<HashMap<isize, Modal>>> = Mutex::new(None);
+
+/// All windows not destroyed yet, in creation order.
+static WINDOWS: Mutex<Vec<isize>> = Mutex::new(Vec::new());
+
+type LastWindowClosed = Arc<Mutex<dyn FnMut() -> bool + Send>>;
+
+static LAST_WINDOW_CLOSED: Mutex<Option<LastWindowClosed>> = Mutex::new(None);
+
+#[cfg(target_os = "linux")]
+type Shortcut = Arc<Mutex<dyn FnMut(&Window) + Send>>;
+
+/// Shortcut callbacks by window and raw `(modifiers, key, ext_key)`.
+#[cfg(target_os = "linux")]
+static SHORTCUTS: Mutex<Option<HashMap<(isize, c_int, u32, c_int), Shortcut>>> = Mutex::new(None);
+
+fn window_destroyed(w: *mut uiWindow) -> Result<(), UiError> {
+    modify_callback!(MODALS, m, { m.get_mut(&(w as _)).map(|m| m.closed = true) })?;
+    #[cfg(target_os = "linux")]
+    {
+        modify_callback!(SHORTCUTS, m, { Some(m.retain(|k, _| k.0 != w as isize)) })?;
+        crate::window_state::window_destroyed(&Window { _inner: w })?;
+    }
+
+    let mut windows = WINDOWS.lock()?;
+    windows.retain(|p| *p != w as isize);
+    if !windows.is_empty() {
+        return Ok(());
+    }
+    drop(windows);
+
+    // The lock is released so the hook can register or clear callbacks.
+    let quit = match LAST_WINDOW_CLOSED.lock()?.clone() {
+        Some(f) => f.lock().map(|mut f| f())?,
+        None => true,
+    };
+    if quit {
+        unsafe { uiQuit() }
+    }
+    Ok(())
+}
+
+unsafe extern "C" fn destroy_on_closing(_w: *mut uiWindow, _data: *mut c_void) -> c_int {
+    1
+}
+
+unsafe extern "C" fn on_destroying(w: *mut uiWindow, _data: *mut c_void) {
+    if let Err(e) = window_destroyed(w) {
+        error!("An error was occurred in on_destroying: {}", e);
+    }
+}
+
+#[cfg(target_os = "linux")]
+unsafe extern "C" fn on_shortcut(
+    w: *mut uiWindow,
+    modifiers: c_int,
+    key: u32,
+    ext_key: c_int,
+    _data: *mut c_void,
+) {
+    // The map is not kept locked, so the callback can close the window or change shortcuts.
+    let f = modify_callback!(SHORTCUTS, m, {
+        m.get(&(w as _, modifiers, key, ext_key)).cloned()
+    });
+    let f = match f {
+        Ok(Some(f)) => f,
+        Ok(None) => return,
+        Err(e) => {
+            error!("An error was occurred in on_shortcut: {}", e);
+            return;
+        }
+    };
+    match f.lock() {
+        Ok(mut f) => f(&Window { _inner: w }),
+        Err(e) => error!("An error was occurred in on_shortcut: {}", e),
+    }
+}
+
+/// Returns all windows not destroyed yet, in creation order.
+pub fn windows() -> Result<Vec<Window>, UiError> {
+    Ok(WINDOWS
+        .lock()?
+        .iter()
+        .map(|p| Window { _inner: *p as _ })
+        .collect())
+}
+
+/// Returns the window that has the keyboard focus.
+///
+/// # returns
+/// * The focused window, `None` if no window of the application is focused.
+pub fn active_window() -> Result<Option<Window>, UiError> {
+    Ok(windows()?.into_iter().find(Window::focused))
+}
+
+/// Registers a callback for when the last window was destroyed.
+///
+/// # arguments
+/// * `f`: Callback function.
+///          Return:
+///          `true` to quit the main loop.
+///
+/// # note
+/// * Only one callback can be registered at a time.
+/// * Without a callback the main loop quits when the last window was destroyed.
+pub fn on_last_window_closed<F>(f: F) -> Result<(), UiError>
+where
+    F: FnMut() -> bool + Send + 'static,
+{
+    *LAST_WINDOW_CLOSED.lock()? = Some(Arc::new(Mutex::new(f)));
+    Ok(())
+}
+
+/// Unregisters a callback for when the last window was destroyed, so the main loop quits again.
+pub fn clear_last_window_closed() -> Result<(), UiError> {
+    *LAST_WINDOW_CLOSED.lock()? = None;
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn screen_work_area_of(w: *mut uiWindow) -> (i32, i32, i32, i32) {
+    let (mut x, mut y, mut width, mut height) = (0, 0, 0, 0);
+    unsafe { uiScreenWorkArea(w, &mut x, &mut y, &mut width, &mut height) };
+    (x, y, width, height)
+}
+
+/// Returns the area of the primary screen not covered by panels, docks or task bars.
+///
+/// # returns
+/// * The position and size of the work area as `(x, y, width, height)`.
+///
+/// # note
+/// * Only available on Linux for now.
+#[cfg(target_os = "linux")]
+pub fn screen_work_area() -> (i32, i32, i32, i32) {
+    screen_work_area_of(null_mut())
+}
+
+fn init_window(ptr: *mut uiWindow) {
+    unsafe {
+        uiWindowOnClosing(ptr, Some(destroy_on_closing), null_mut());
+        uiWindowOnDestroying(ptr, Some(on_destroying), null_mut());
+    }
+    WINDOW_CREATED.store(true, Ordering::Relaxed);
+    match WINDOWS.lock() {
+        Ok(mut w) => w.push(ptr as _),
+        Err(e) => error!("An error was occurred in init_window: {}", e),
+    }
+}
+
 #[derive(Debug)]
 pub struct Window {
     _inner: *mut uiWindow,
@@ -36,6 +218,8 @@ impl AsRef<Self> for Window {
 }
 
 impl Control for Window {
+    const TYPE_SIGNATURE: Option<u32> = Some(0x57696E64);
+
     fn as_ptr_mut(&self) -> *mut uiControl {
         self._inner as _
     }
@@ -73,6 +257,26 @@ impl Window {
         unsafe { uiWindowFocused(self._inner) != 0 }
     }
 
+    /// Returns the control inside the window that has keyboard focus.
+    ///
+    /// # returns
+    /// * The focused control, `None` if no control has focus or it is not a `C`.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn focused_control<C>(&self) -> Option<C>
+    where
+        C: Control,
+    {
+        let ptr = unsafe { uiWindowFocusedControl(self._inner) };
+        if ptr.is_null() || C::TYPE_SIGNATURE != Some(unsafe { (*ptr).TypeSignature }) {
+            None
+        } else {
+            Some(C::from_ptr(ptr))
+        }
+    }
+
     define_callback_function!(_on_focus_changed, uiWindowOnFocusChanged, (), uiWindow);
     /// Registers a callback for when the window focus changes.
     ///
@@ -116,6 +320,7 @@ impl Window {
     ///
     /// # note
     /// * Only one callback can be registered at a time.
+    /// * Without a callback the window is destroyed when closed.
     pub fn on_closing<'a, 'b, F, T>(&self, mut f: F, data: &'a mut T) -> Result<(), UiError>
     where
         T: Copy + 'b,
@@ -125,12 +330,14 @@ impl Window {
         self._on_closing(Some(move |w, d| if f(w, d) { 1 } else { 0 }), data)
     }
 
-    /// Unregisters a callback for when the window is to be closed.
+    /// Unregisters a callback for when the window is to be closed, so closing destroys the window again.
     pub fn clear_closing(&self) -> Result<(), UiError> {
         #[allow(unused_assignments)]
         let mut func = Some(|_, _| 0);
         func = None;
-        self._on_closing(func, &mut ())
+        self._on_closing(func, &mut ())?;
+        unsafe { uiWindowOnClosing(self._inner, Some(destroy_on_closing), null_mut()) }
+        Ok(())
     }
 
     define_callback_function!(
@@ -350,6 +557,265 @@ impl Window {
         unsafe { uiWindowSetResizeable(self._inner, resizeable as _) }
     }
 
+    /// Minimizes the window.
+    ///
+    /// # note
+    /// * The state changes asynchronously, use `on_state_changed()` to get notified.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn minimize(&self) {
+        unsafe { uiWindowMinimize(self._inner) }
+    }
+
+    /// Returns whether or not the window is minimized.
+    ///
+    /// # returns
+    /// * `true` if the window is minimized, `false` otherwise. [Default: `false`]
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn is_minimized(&self) -> bool {
+        unsafe { uiWindowMinimized(self._inner) != 0 }
+    }
+
+    /// Maximizes the window.
+    ///
+    /// # note
+    /// * The state changes asynchronously, use `on_state_changed()` to get notified.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn maximize(&self) {
+        unsafe { uiWindowMaximize(self._inner) }
+    }
+
+    /// Returns whether or not the window is maximized.
+    ///
+    /// # returns
+    /// * `true` if the window is maximized, `false` otherwise. [Default: `false`]
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn is_maximized(&self) -> bool {
+        unsafe { uiWindowMaximized(self._inner) != 0 }
+    }
+
+    /// Restores a minimized or maximized window to its normal state.
+    ///
+    /// # note
+    /// * The state changes asynchronously, use `on_state_changed()` to get notified.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn restore(&self) {
+        unsafe { uiWindowRestore(self._inner) }
+    }
+
+    /// Returns whether or not the window is kept above other windows.
+    ///
+    /// # returns
+    /// * `true` if the window is kept above, `false` otherwise. [Default: `false`]
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn keep_above(&self) -> bool {
+        unsafe { uiWindowKeepAbove(self._inner) != 0 }
+    }
+
+    /// Sets whether or not the window is kept above other windows.
+    /// This method is merely a hint and may be ignored by the system.
+    ///
+    /// # arguments
+    /// * `keep_above`: `true` to keep the window above, `false` otherwise.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_keep_above(&self, keep_above: bool) {
+        unsafe { uiWindowSetKeepAbove(self._inner, keep_above as _) }
+    }
+
+    #[cfg(target_os = "linux")]
+    define_callback_function!(_on_state_changed, uiWindowOnStateChanged, (), uiWindow);
+    /// Registers a callback for when the window is minimized, maximized, restored,
+    /// enters or leaves fullscreen, or changes its keep above state.
+    ///
+    /// # arguments
+    /// * `f`: Callback function.
+    ///          @p sender Back reference to the instance that triggered the callback.
+    ///          @p senderData User data registered with the sender instance.
+    ///
+    /// * `data`: User data to be passed to the callback.
+    ///
+    /// # note
+    /// * Only one callback can be registered at a time.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn on_state_changed<'a, 'b, F, T>(&self, f: F, data: &'a mut T) -> Result<(), UiError>
+    where
+        T: Copy + 'b,
+        F: FnMut(Self, &'b mut T) + Send + 'static,
+        'b: 'a,
+    {
+        self._on_state_changed(Some(f), data)
+    }
+
+    /// Unregisters a callback for when the window state changes.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn clear_state_changed(&self) -> Result<(), UiError> {
+        #[allow(unused_assignments)]
+        let mut func = Some(|_, _| ());
+        func = None;
+        self._on_state_changed(func, &mut ())
+    }
+
+    /// Asks the window to close, as if the user clicked its close button.
+    /// The callback registered via `on_closing()` decides whether the window gets destroyed.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    ///
+    /// # warning
+    /// * The window must not be used afterwards if it was destroyed.
+    #[cfg(target_os = "linux")]
+    pub fn close(&self) {
+        unsafe { uiWindowClose(self._inner) }
+    }
+
+    /// Adds a keyboard shortcut to the window.
+    ///
+    /// # arguments
+    /// * `accelerator`: The shortcut, e.g. `"Ctrl+Shift+P".parse()?`.
+    /// * `f`: Callback function.
+    ///          @p window The window the shortcut was pressed in.
+    ///
+    /// # note
+    /// * Adding a shortcut that already exists replaces it.
+    /// * Shortcuts also work in windows without a menubar.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn add_shortcut<F>(&self, accelerator: Accelerator, f: F) -> Result<(), UiError>
+    where
+        F: FnMut(&Window) + Send + 'static,
+    {
+        let (modifiers, key, ext_key) = Self::shortcut_raw(accelerator);
+        modify_callback!(SHORTCUTS, m, {
+            m.insert(
+                (self._inner as _, modifiers, key, ext_key),
+                Arc::new(Mutex::new(f)),
+            )
+        })?;
+        Ok(unsafe {
+            uiWindowAddShortcut(
+                self._inner,
+                modifiers,
+                key,
+                ext_key,
+                Some(on_shortcut),
+                null_mut(),
+            )
+        })
+    }
+
+    /// Removes a keyboard shortcut from the window.
+    ///
+    /// # arguments
+    /// * `accelerator`: The shortcut passed to `add_shortcut()`.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn remove_shortcut(&self, accelerator: Accelerator) -> Result<(), UiError> {
+        let (modifiers, key, ext_key) = Self::shortcut_raw(accelerator);
+        modify_callback!(SHORTCUTS, m, {
+            m.remove(&(self._inner as _, modifiers, key, ext_key))
+        })?;
+        Ok(unsafe { uiWindowRemoveShortcut(self._inner, modifiers, key, ext_key) })
+    }
+
+    #[cfg(target_os = "linux")]
+    fn shortcut_raw(accelerator: Accelerator) -> (c_int, u32, c_int) {
+        let (key, ext_key) = accelerator.key.to_raw();
+        (accelerator.modifiers.bits() as _, key, ext_key)
+    }
+
+    /// Sets the window icon.
+    ///
+    /// # arguments
+    /// * `icon`: The icon, `None` to use the default icon.
+    ///
+    /// # note
+    /// * The image is copied, it can be dropped afterwards.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_icon(&self, icon: Option<&Image>) {
+        let icon = match icon {
+            None => null_mut(),
+            Some(i) => i.as_ptr_mut(),
+        };
+
+        unsafe { uiWindowSetIcon(self._inner, icon as _) }
+    }
+
+    /// Sets the minimum size the user can shrink the window content to.
+    ///
+    /// # arguments
+    /// * `width`: Minimum window content width, `0` for no limit.
+    /// * `height`: Minimum window content height, `0` for no limit.
+    ///
+    /// # note
+    /// * The menubar is not part of the content, the window itself may be larger.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_min_content_size(&self, width: i32, height: i32) {
+        unsafe { uiWindowSetMinContentSize(self._inner, width, height) }
+    }
+
+    /// Sets the maximum size the user can grow the window content to.
+    ///
+    /// # arguments
+    /// * `width`: Maximum window content width, `0` for no limit.
+    /// * `height`: Maximum window content height, `0` for no limit.
+    ///
+    /// # note
+    /// * The menubar is not part of the content, the window itself may be larger.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_max_content_size(&self, width: i32, height: i32) {
+        unsafe { uiWindowSetMaxContentSize(self._inner, width, height) }
+    }
+
+    /// Sets the aspect ratio the user can resize the window to.
+    ///
+    /// # arguments
+    /// * `ratio`: Width and height of the ratio, e.g. `(16., 9.)`, `None` for no fixed ratio.
+    ///
+    /// # note
+    /// * The ratio applies to the whole window, including the menubar.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn set_aspect_ratio(&self, ratio: Option<(f64, f64)>) {
+        let (width, height) = ratio.unwrap_or_default();
+        unsafe { uiWindowSetAspectRatio(self._inner, width, height) }
+    }
+
+    /// Returns the area of the window's screen not covered by panels, docks or task bars.
+    ///
+    /// # returns
+    /// * The position and size of the work area as `(x, y, width, height)`.
+    ///
+    /// # note
+    /// * Windows not shown yet report the work area of the primary screen.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn work_area(&self) -> (i32, i32, i32, i32) {
+        screen_work_area_of(self._inner)
+    }
+
     /// Creates a new uiWindow.
     ///
     /// # arguments
@@ -364,41 +830,125 @@ impl Window {
         let title = CString::new(title)?;
         let has_menubar = if has_menubar { 1 } else { 0 };
         let ptr = unsafe { uiNewWindow(title.as_ptr(), width, height, has_menubar) };
+        init_window(ptr);
         Ok(Self { _inner: ptr }.into())
     }
 
+    /// Creates a new dialog window.
+    /// The dialog is modal: it stays on top of `parent` and blocks input to all other windows
+    /// while it is visible.
+    ///
+    /// # arguments
+    /// * `parent`: Parent window.
+    /// * `title`: Window title text.
+    /// * `width`: Window width.
+    /// * `height`: Window height.
+    ///
+    /// # returns
+    /// * A new uiWindow instance.
+    ///
+    /// # note
+    /// * Unlike regular windows, dialogs are destroyed by default when the user closes them.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn new_dialog(
+        parent: &Self,
+        title: &str,
+        width: i32,
+        height: i32,
+    ) -> Result<Self, NulError> {
+        let title = CString::new(title)?;
+        let ptr = unsafe { uiNewDialogWindow(parent._inner, title.as_ptr(), width, height) };
+        init_window(ptr);
+        Ok(Self { _inner: ptr })
+    }
+
+    /// Shows the window and runs the event loop until it is closed.
+    ///
+    /// # returns
+    /// * The result passed to `end_modal()`, `T::default()` if the window was closed otherwise.
+    ///
+    /// # note
+    /// * The loop also ends when the application quits.
+    /// * The window must not be used afterwards unless the loop ended by quitting.
+    pub fn run_modal<T>(&self) -> T
+    where
+        T: Default + Send + 'static,
+    {
+        let key = self._inner as isize;
+        if let Err(e) = modify_callback!(MODALS, m, { m.insert(key, Default::default()) }) {
+            error!("An error was occurred in run_modal: {}", e);
+            return Default::default();
+        }
+
+        self.show();
+        while let Ok(Some(false)) = modify_callback!(MODALS, m, { m.get(&key).map(|m| m.closed) }) {
+            if unsafe { uiMainStep(1) } == 0 {
+                break;
+            }
+        }
+
+        modify_callback!(MODALS, m, { m.remove(&key) })
+            .ok()
+            .flatten()
+            .and_then(|m| m.result)
+            .and_then(|r| r.downcast::<T>().ok())
+            .map_or_else(Default::default, |r| *r)
+    }
+
+    /// Ends `run_modal()` with a result and destroys the window.
+    ///
+    /// # arguments
+    /// * `result`: Value returned by `run_modal()`. Its type has to match, otherwise `run_modal()`
+    ///   returns `T::default()`.
+    pub fn end_modal<T>(&self, result: T)
+    where
+        T: Send + 'static,
+    {
+        let key = self._inner as isize;
+        if let Err(e) = modify_callback!(MODALS, m, {
+            m.get_mut(&key).map(|m| m.result = Some(Box::new(result)))
+        }) {
+            error!("An error was occurred in end_modal: {}", e);
+        }
+        self.destroy();
+    }
+
     /// File chooser dialog window to select a single file.
     ///
     /// # returns
-    /// * File path, `NULL` on cancel.
-    pub fn open_file(&self) -> Result<Option<PathBuf>, Utf8Error> {
+    /// * File path, `None` on cancel.
+    ///
+    /// # see
+    /// - `FileDialog` for filters, initial paths and multiple selection (Linux only for now).
+    pub fn open_file(&self) -> Option<PathBuf> {
         let ptr = unsafe { uiOpenFile(self._inner) };
         if ptr.is_null() {
-            return Ok(None);
+            return None;
         }
 
-        let path = PathBuf::from(unsafe { CStr::from_ptr(ptr).to_str()? });
+        let path = unsafe { path_from_c(ptr) };
         unsafe {
             uiFreeText(ptr);
         }
-        Ok(Some(path))
+        Some(path)
     }
 
     /// Folder chooser dialog window to select a single folder.
     ///
     /// # returns
-    /// Folder path, `NULL` on cancel.
-    pub fn open_folder(&self) -> Result<Option<PathBuf>, Utf8Error> {
+    /// Folder path, `None` on cancel.
+    pub fn open_folder(&self) -> Option<PathBuf> {
         let ptr = unsafe { uiOpenFolder(self._inner) };
         if ptr.is_null() {
-            return Ok(None);
+            return None;
         }
 
-        let path = PathBuf::from(unsafe { CStr::from_ptr(ptr).to_str()? });
+        let path = unsafe { path_from_c(ptr) };
         unsafe {
             uiFreeText(ptr);
         }
-        Ok(Some(path))
+        Some(path)
     }
 
     /// Save file dialog window.
@@ -406,18 +956,18 @@ impl Window {
     /// file path already exist on the system.
     ///
     /// # returns
-    /// * File path, `NULL` on cancel.
-    pub fn save_file(&self) -> Result<Option<PathBuf>, Utf8Error> {
+    /// * File path, `None` on cancel.
+    pub fn save_file(&self) -> Option<PathBuf> {
         let ptr = unsafe { uiSaveFile(self._inner) };
         if ptr.is_null() {
-            return Ok(None);
+            return None;
         }
 
-        let path = PathBuf::from(unsafe { CStr::from_ptr(ptr).to_str()? });
+        let path = unsafe { path_from_c(ptr) };
         unsafe {
             uiFreeText(ptr);
         }
-        Ok(Some(path))
+        Some(path)
     }
 
     /// Message box dialog window.
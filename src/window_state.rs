@@ -0,0 +1,236 @@
+use std::{
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs::{read_to_string, write},
+    io::{Error as IoError, ErrorKind, Result as IoResult},
+    path::Path,
+    str::FromStr,
+};
+#[cfg(target_os = "linux")]
+use {
+    crate::{Control, Window, error::UiError, modify_callback},
+    log::error,
+    std::{collections::HashMap, path::PathBuf, sync::Mutex},
+};
+
+/// The geometry of a window, which can be saved and reapplied later.
+///
+/// Saved as `key=value` lines, one per field.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WindowState {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub maximized: bool,
+    pub fullscreen: bool,
+}
+
+/// Normal (not maximized or fullscreen) geometry and file path of persisted windows.
+#[cfg(target_os = "linux")]
+static PERSISTED: Mutex<Option<HashMap<isize, (WindowState, PathBuf)>>> = Mutex::new(None);
+
+impl WindowState {
+    /// Takes a snapshot of the window geometry.
+    ///
+    /// # arguments
+    /// * `window`: The window.
+    ///
+    /// # note
+    /// * Position and size of maximized or fullscreen windows are the maximized or fullscreen
+    ///   ones. Use `persist()` to keep track of the normal geometry.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn from_window(window: &Window) -> Self {
+        let (x, y) = window.position();
+        let (width, height) = window.content_size();
+        Self {
+            x,
+            y,
+            width,
+            height,
+            maximized: window.is_maximized(),
+            fullscreen: window.fullscreen(),
+        }
+    }
+
+    /// Applies the geometry to a window.
+    ///
+    /// # arguments
+    /// * `window`: The window.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn apply(&self, window: &Window) {
+        window.set_position(self.x, self.y);
+        window.set_content_size(self.width, self.height);
+        if self.maximized {
+            window.maximize();
+        }
+        if self.fullscreen {
+            window.set_fullscreen(true);
+        }
+    }
+
+    /// Reads a saved state from a file.
+    ///
+    /// # arguments
+    /// * `path`: The file written by `save()`.
+    pub fn load<P: AsRef<Path>>(path: P) -> IoResult<Self> {
+        read_to_string(path)?.parse()
+    }
+
+    /// Writes the state to a file.
+    ///
+    /// # arguments
+    /// * `path`: The file, created or overwritten.
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> IoResult<()> {
+        write(path, self.to_string())
+    }
+
+    /// Restores the window geometry from a file and saves it there again when the window is
+    /// destroyed. A missing or invalid file is ignored.
+    ///
+    /// # arguments
+    /// * `window`: The window.
+    /// * `path`: The file the state is kept in.
+    ///
+    /// # note
+    /// * No callbacks of the window are registered. A window destroyed while maximized or
+    ///   fullscreen saves the normal geometry it had when passed here, unless `remember()` is
+    ///   called from the `on_position_changed` and `on_content_size_changed` callbacks.
+    /// * Errors while saving on destruction are logged. Call `save_persisted()` to handle them.
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn persist<P: AsRef<Path>>(window: &Window, path: P) -> Result<(), UiError> {
+        let path = path.as_ref().to_path_buf();
+        let state = Self::load(&path).ok();
+        if let Some(state) = state {
+            state.apply(window);
+        }
+        let normal = Self {
+            maximized: false,
+            fullscreen: false,
+            ..state.unwrap_or_else(|| Self::from_window(window))
+        };
+        modify_callback!(PERSISTED, p, {
+            p.insert(window.as_ptr_mut() as _, (normal, path))
+        })?;
+        Ok(())
+    }
+
+    /// Remembers the current geometry of a persisted window as its normal geometry,
+    /// unless the window is maximized or fullscreen.
+    ///
+    /// # arguments
+    /// * `window`: A window passed to `persist()` before.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn remember(window: &Window) -> Result<(), UiError> {
+        if window.is_maximized() || window.fullscreen() {
+            return Ok(());
+        }
+
+        let current = Self::from_window(window);
+        modify_callback!(PERSISTED, p, {
+            p.get_mut(&(window.as_ptr_mut() as _))
+                .map(|(s, _)| *s = current)
+        })?;
+        Ok(())
+    }
+
+    /// Saves the geometry of a persisted window to the file passed to `persist()`.
+    /// Maximized and fullscreen windows keep their last remembered normal geometry.
+    ///
+    /// # arguments
+    /// * `window`: A window passed to `persist()` before.
+    ///
+    /// # returns
+    /// * `false` if the window was not passed to `persist()` before.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn save_persisted(window: &Window) -> Result<bool, UiError> {
+        Self::remember(window)?;
+        let Some((normal, path)) = modify_callback!(PERSISTED, p, {
+            p.get(&(window.as_ptr_mut() as _)).cloned()
+        })?
+        else {
+            return Ok(false);
+        };
+
+        let state = Self {
+            maximized: window.is_maximized(),
+            fullscreen: window.fullscreen(),
+            ..normal
+        };
+        state.save(&path)?;
+        Ok(true)
+    }
+
+    /// Stops keeping the geometry of a persisted window, so it is not saved when destroyed.
+    ///
+    /// # arguments
+    /// * `window`: A window passed to `persist()` before.
+    ///
+    /// # returns
+    /// * `false` if the window was not passed to `persist()` before.
+    ///
+    /// # note
+    /// * Only available on Linux for now.
+    #[cfg(target_os = "linux")]
+    pub fn forget(window: &Window) -> Result<bool, UiError> {
+        let removed = modify_callback!(PERSISTED, p, { p.remove(&(window.as_ptr_mut() as _)) })?;
+        Ok(removed.is_some())
+    }
+}
+
+/// Saves the state of a persisted window and forgets its file when the window is destroyed.
+/// Called while the window is still valid.
+#[cfg(target_os = "linux")]
+pub(crate) fn window_destroyed(window: &Window) -> Result<(), UiError> {
+    if let Err(e) = WindowState::save_persisted(window) {
+        error!("Failed to save the state of a destroyed window: {}", e);
+    }
+    WindowState::forget(window)?;
+    Ok(())
+}
+
+impl Display for WindowState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        writeln!(f, "x={}", self.x)?;
+        writeln!(f, "y={}", self.y)?;
+        writeln!(f, "width={}", self.width)?;
+        writeln!(f, "height={}", self.height)?;
+        writeln!(f, "maximized={}", self.maximized)?;
+        writeln!(f, "fullscreen={}", self.fullscreen)
+    }
+}
+
+impl FromStr for WindowState {
+    type Err = IoError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |line: &str| IoError::new(ErrorKind::InvalidData, line.to_owned());
+        let mut state = Self::default();
+        for line in s.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let (key, value) = line.split_once('=').ok_or_else(|| invalid(line))?;
+            let value = value.trim();
+            match key.trim() {
+                "x" => state.x = value.parse().map_err(|_| invalid(line))?,
+                "y" => state.y = value.parse().map_err(|_| invalid(line))?,
+                "width" => state.width = value.parse().map_err(|_| invalid(line))?,
+                "height" => state.height = value.parse().map_err(|_| invalid(line))?,
+                "maximized" => state.maximized = value.parse().map_err(|_| invalid(line))?,
+                "fullscreen" => state.fullscreen = value.parse().map_err(|_| invalid(line))?,
+                // unknown keys are skipped so newer versions can add fields
+                _ => (),
+            }
+        }
+        Ok(state)
+    }
+}
@@ -0,0 +1,22 @@
+use system_ui::*;
+
+pub fn test_accelerator() -> anyhow::Result<()> {
+    let accelerator: Accelerator = "Ctrl+S".parse()?;
+    assert_eq!(Modifiers::CTRL, accelerator.modifiers);
+    assert_eq!(Key::Char('s'), accelerator.key);
+    assert_eq!("Ctrl+S", accelerator.to_string());
+
+    let accelerator: Accelerator = "shift+ctrl+F5".parse()?;
+    assert_eq!(Modifiers::CTRL | Modifiers::SHIFT, accelerator.modifiers);
+    assert_eq!(Key::F(5), accelerator.key);
+    assert_eq!("Ctrl+Shift+F5", accelerator.to_string());
+
+    let accelerator: Accelerator = "Ctrl++".parse()?;
+    assert_eq!(Key::Char('+'), accelerator.key);
+    assert_eq!(Key::Escape, "Esc".parse::<Accelerator>()?.key);
+    assert!("Ctrl+".parse::<Accelerator>().is_err());
+    assert!("Hyper+A".parse::<Accelerator>().is_err());
+    assert!("F13".parse::<Accelerator>().is_err());
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use system_ui::*;
+
+pub fn test_action() -> anyhow::Result<()> {
+    let action = Action::new("file.save", "保存")?;
+    assert_eq!("file.save", action.id());
+    assert!(Action::find("file.save")?.is_some());
+    assert!(Action::find("file.open")?.is_none());
+    // 丢弃所有句柄后动作被注销
+    drop(Action::new("file.close", "关闭")?);
+    assert!(Action::find("file.close")?.is_none());
+
+    let menu = Menu::new("动作")?;
+    let item = menu.append_item("")?;
+    action.bind_menu_item(&item)?;
+    assert_eq!("保存", item.text()?);
+    let button = Button::new("")?;
+    action.bind_button(&button)?;
+    assert_eq!("保存", button.text()?);
+
+    action.set_label("另存为")?;
+    assert_eq!("另存为", item.text()?);
+    assert_eq!("另存为", button.text()?);
+
+    // 禁用动作会禁用所有绑定的控件
+    action.set_enabled(false)?;
+    assert!(!button.enabled());
+    assert!(!action.trigger()?);
+    action.set_enabled(true)?;
+    assert!(button.enabled());
+
+    action.on_triggered(|a| {
+        a.set_checked(true).unwrap();
+    })?;
+    assert!(action.trigger()?);
+    assert!(action.checked()?);
+    action.clear_triggered()?;
+    assert!(!action.trigger()?);
+    // 处理函数中清除自身后不会被恢复
+    action.on_triggered(|a| a.clear_triggered().unwrap())?;
+    assert!(action.trigger()?);
+    assert!(!action.trigger()?);
+
+    action.set_accelerator(Some("Ctrl+S".parse()?))?;
+    assert_eq!(Some("Ctrl+S".parse()?), action.accelerator()?);
+    action.unbind(&button)?;
+
+    // 删除的菜单项和销毁的按钮会自动解绑
+    let button = Button::new("")?;
+    action.bind_button(&button)?;
+    item.delete();
+    button.destroy();
+    action.set_label("保存")?;
+    action.set_enabled(false)?;
+    action.set_accelerator(None)?;
+
+    Ok(())
+}
@@ -5,6 +5,12 @@ pub fn test_button() -> anyhow::Result<()> {
     assert_eq!("测试", button.text()?);
     button.set_text("test")?;
     assert_eq!("test", button.text()?);
+    // 设置和清除工具提示
+    #[cfg(target_os = "linux")]
+    {
+        button.set_tooltip(Some("提示"))?;
+        button.set_tooltip(None)?;
+    }
 
     Ok(())
 }
@@ -0,0 +1,14 @@
+use system_ui::*;
+
+pub fn test_clipboard() -> anyhow::Result<()> {
+    clipboard::set_text("剪贴板")?;
+    assert_eq!(Some("剪贴板".into()), clipboard::get_text()?);
+    let image = Image::new(2., 2.);
+    image.append(&[255; 2 * 2 * 4], 2, 2, 2 * 4);
+    clipboard::set_image(&image);
+    assert!(clipboard::get_image().is_some());
+    clipboard::on_changed(|| ())?;
+    clipboard::clear_changed()?;
+
+    Ok(())
+}
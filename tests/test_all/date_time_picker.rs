@@ -3,10 +3,26 @@ use system_ui::*;
 
 pub fn test_date_time_picker() -> anyhow::Result<()> {
     use chrono::{DateTime, Local};
-    let picker = DateTimePicker::new_date();
+    let picker = DatePicker::new();
+    let date = Date::new(2024, 2, 29);
+    picker.set_date(date)?;
+    assert_eq!(date, picker.date());
+    let min = Date::new(2025, 1, 1);
+    picker.set_minimum(Some(min))?;
+    assert_eq!(Some(min), picker.minimum()?);
+    assert_eq!(min, picker.date());
+
+    let picker = TimePicker::new();
+    let time = Time::new(8, 30, 0);
+    picker.set_time(time)?;
+    assert_eq!(time, picker.time());
+    let max = Time::new(6, 0, 0);
+    picker.set_maximum(Some(max))?;
+    assert_eq!(max, picker.time());
+
+    let picker = DateTimePicker::new();
     let _time: DateTime<Local> = picker.time().into();
     // println!("{:?}", _time);
-    let picker = DateTimePicker::new();
     let time = SystemTime::UNIX_EPOCH + Duration::from_secs(10000);
     picker.set_time(time)?;
     assert_eq!(time, picker.time());
@@ -13,5 +13,33 @@ pub fn test_entry() -> anyhow::Result<()> {
     entry.set_read_only(true);
     assert!(entry.read_only());
 
+    #[cfg(target_os = "linux")]
+    {
+        // 测试占位符和最大长度
+        entry.set_placeholder(Some("请输入"))?;
+        entry.set_placeholder(None)?;
+        assert_eq!(0, entry.max_length());
+        entry.set_max_length(1);
+        assert_eq!(1, entry.max_length());
+        assert_eq!("测", entry.text()?);
+        entry.set_max_length(0);
+
+        // 测试选择和光标位置
+        entry.set_text("hello")?;
+        entry.select_range(1, 3);
+        assert_eq!(Some((1, 3)), entry.selection());
+        assert_eq!(3, entry.cursor_position());
+        entry.select_range(2, 2);
+        assert_eq!(None, entry.selection());
+
+        // 测试密码遮盖
+        let password = Entry::new_password();
+        assert!(!password.visibility());
+        password.set_visibility(true);
+        assert!(password.visibility());
+        password.on_activate(|_, _| (), &mut ())?;
+        password.clear_activate()?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,22 @@
+use system_ui::*;
+
+pub fn test_file_dialog() -> anyhow::Result<()> {
+    let filter = FileFilter::from("Images: *.png; *.jpg");
+    assert_eq!(FileFilter::new("Images", &["*.png", "*.jpg"]), filter);
+    let filter = FileFilter::from("*.txt");
+    assert_eq!(FileFilter::new("*.txt", &["*.txt"]), filter);
+    let filter = FileFilter::from("所有文件:");
+    assert_eq!(vec!["*"], filter.patterns);
+
+    // 对话框需要用户交互，这里只检查构建器
+    #[cfg(target_os = "linux")]
+    let _dialog = FileDialog::new()
+        .title("打开图片")
+        .directory("/tmp")
+        .file_name("a.png")
+        .filter("Images: *.png;*.jpg")
+        .filter(FileFilter::new("All", &["*"]))
+        .show_hidden(true);
+
+    Ok(())
+}
@@ -6,5 +6,60 @@ pub fn test_label() -> anyhow::Result<()> {
     label.set_text("test")?;
     assert_eq!("test", label.text()?);
 
+    #[cfg(target_os = "linux")]
+    {
+        // 对齐、换行和省略
+        assert_eq!(TextAlign::Left, label.align());
+        label.set_align(TextAlign::Right);
+        assert_eq!(TextAlign::Right, label.align());
+        assert!(!label.wrap());
+        label.set_wrap(true);
+        assert!(label.wrap());
+        assert_eq!(-1, label.max_width());
+        label.set_max_width(40);
+        assert_eq!(40, label.max_width());
+        assert_eq!(Ellipsize::None, label.ellipsize());
+        label.set_ellipsize(Ellipsize::Middle);
+        assert_eq!(Ellipsize::Middle, label.ellipsize());
+
+        // 可选择和标记文本
+        assert!(!label.selectable());
+        label.set_selectable(true);
+        assert!(label.selectable());
+        assert!(label.set_markup("<b>粗体</b> &amp; <i>斜体</i>")?);
+        assert_eq!("粗体 & 斜体", label.text()?);
+        assert!(!label.set_markup("<b>未闭合")?);
+        assert_eq!("粗体 & 斜体", label.text()?);
+    }
+
+    // 字体和颜色
+    #[cfg(target_os = "linux")]
+    {
+        let font = FontDescriptor {
+            weight: TextWeight::BOLD,
+            italic: TextItalic::Italic,
+            stretch: TextStretch::Condensed,
+            ..FontDescriptor::new("Sans \"Pro\"", 12.5)
+        };
+        label.set_font(Some(&font))?;
+        let set = label.font();
+        assert_eq!(font.family, set.family);
+        assert!((font.size - set.size).abs() < 0.1);
+        assert_eq!(font.weight, set.weight);
+        assert_eq!(font.italic, set.italic);
+        assert_eq!(font.stretch, set.stretch);
+        label.set_text_color(Some(Color::rgb(0xcc0000)));
+        label.set_background_color(Some(Color::new(1., 1., 0.8, 1.)));
+        label.set_text_color(None);
+        label.set_background_color(None);
+        label.set_font(None)?;
+        assert_eq!(TextItalic::Normal, label.font().italic);
+        assert!(
+            label
+                .set_font(Some(&FontDescriptor::new("a\0b", 12.)))
+                .is_err()
+        );
+    }
+
     Ok(())
 }
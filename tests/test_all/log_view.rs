@@ -0,0 +1,74 @@
+use {
+    log::{Log, Record},
+    std::thread,
+    system_ui::*,
+};
+
+pub fn test_log_view() -> anyhow::Result<()> {
+    let color = Color::rgb(0xff8000);
+    assert_eq!(Color::new(1., 128. / 255., 0., 1.), color);
+
+    let view = LogView::new();
+    // 日志视图默认只读并自动滚动
+    assert!(view.read_only());
+    assert!(view.auto_scroll());
+    assert_eq!(1000, view.max_lines()?);
+    view.set_max_lines(2)?;
+    assert_eq!(2, view.max_lines()?);
+    view.set_level_color(Level::Warn, Some(color))?;
+    view.set_level(LevelFilter::Warn)?;
+    assert_eq!(LevelFilter::Warn, view.level()?);
+
+    // 彩色追加和按行删除
+    view.append_with_color("第一行\n", color)?;
+    view.append("第二行\n第三行\n")?;
+    assert_eq!(4, view.line_count());
+    assert_eq!(Some(color), view.line_color(0));
+    assert_eq!(None, view.line_color(1));
+    view.delete_lines(0, 2);
+    assert_eq!(Some("第三行".into()), view.line(0)?);
+
+    // 在其他线程中记录日志，由主循环显示
+    let handle = view.handle();
+    thread::spawn(move || {
+        let records = [
+            (Level::Error, 1),
+            (Level::Info, 2),
+            (Level::Warn, 3),
+            (Level::Error, 4),
+        ];
+        for (level, n) in records {
+            let args = format_args!("记录{}", n);
+            handle.log(
+                &Record::builder()
+                    .level(level)
+                    .target("test")
+                    .args(args)
+                    .build(),
+            );
+        }
+    })
+    .join()
+    .unwrap();
+    // 没有以换行结尾的文本后记录从新行开始
+    view.append("第四行")?;
+    assert_eq!("第三行\n第四行", view.text()?);
+    crate::run_queued();
+
+    // Info 级别被过滤，超出的最旧行被删除，文本以换行结尾
+    assert_eq!(3, view.line_count());
+    assert_eq!(Some("[WARN ] test: 记录3".into()), view.line(0)?);
+    assert_eq!(Some("[ERROR] test: 记录4".into()), view.line(1)?);
+    assert_eq!(Some(color), view.line_color(0));
+    assert_eq!(Some(Color::rgb(0xcc0000)), view.line_color(1));
+
+    view.delete_lines(0, 10);
+    assert_eq!("", view.text()?);
+
+    // 控件销毁后记录被丢弃
+    let handle = view.handle();
+    view.destroy();
+    assert!(!handle.enabled(Record::builder().level(Level::Error).build().metadata()));
+
+    Ok(())
+}
@@ -5,9 +5,74 @@ pub(super) fn test_menu() -> anyhow::Result<()> {
     let item = menu.append_check_item("省电模式")?;
     item.set_checked(true);
     assert!(item.checked());
+    #[cfg(target_os = "linux")]
+    {
+        item.set_text("节能模式")?;
+        assert_eq!("节能模式", item.text()?);
+        let item = menu.append_item("保存")?;
+        item.set_accelerator(Some("Ctrl+S".parse()?));
+        item.set_accelerator(None);
+        item.delete();
+        let submenu = menu.append_submenu("最近打开")?;
+        submenu.append_item("a.txt")?;
+        submenu.delete();
+    }
     let item = menu.append_quit_item();
     item.disable();
     item.enable();
 
+    #[cfg(target_os = "linux")]
+    {
+        #[derive(Clone, Debug, Eq, Hash, PartialEq)]
+        enum Cmd {
+            Open,
+            Wrap,
+            Recent,
+            About,
+        }
+        let items = menu! {
+            "编辑" => [
+                item(Cmd::Open, "打开", "Ctrl+O"),
+                check(Cmd::Wrap, "自动换行", true),
+                separator,
+                submenu("最近") => [item(Cmd::Recent, "b.txt")],
+            ],
+            "帮助" => [about(Cmd::About)],
+        }
+        .build(|_id, _item, _window| ())?;
+        assert_eq!(Some(true), items.checked(&Cmd::Wrap));
+        assert!(items.set_checked(&Cmd::Wrap, false));
+        assert_eq!(Some(false), items.checked(&Cmd::Wrap));
+        assert_eq!("b.txt", items.get(&Cmd::Recent).unwrap().text()?);
+        let dup = menu! { "重复" => [item(1, "a"), item(1, "b")] }.build(|_, _, _| ());
+        assert!(dup.is_err());
+        let bad = menu! { "错误" => [item(1, "a", "Ctrl+Foo")] }.build(|_, _, _| ());
+        assert!(bad.is_err());
+        // 退出菜单项已在上面创建过
+        let quit = menu! { "退出" => [quit] }.build(|_: &Cmd, _, _| ());
+        assert!(quit.is_err());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        let popup = PopupMenu::new();
+        popup.append_item("复制")?;
+        popup.append_separator();
+        popup
+            .append_submenu("更多")?
+            .append_check_item("自动换行")?;
+        let button = Button::new("右键")?;
+        button.set_context_menu(Some(&popup));
+        button.set_context_menu(None);
+        // 不在窗口中的控件不能弹出菜单
+        assert!(popup.popup(&button).is_err());
+        assert!(popup.popup_at(&button, 0, 0).is_err());
+        popup.append_submenu("临时")?.delete();
+        // 多行文本框的右键菜单应替换内部文本视图的默认菜单
+        let entry = MultiLineEntry::new();
+        entry.set_context_menu(Some(&popup));
+        entry.set_context_menu(None);
+    }
+
     Ok(())
 }
@@ -0,0 +1,31 @@
+use system_ui::*;
+
+pub fn test_message_dialog() -> anyhow::Result<()> {
+    let window = Window::new("message_dialog", 80, 80, false)?;
+
+    // 对话框需要用户交互，这里只检查无效按钮的校验，校验失败时不会显示对话框
+    let dialog = MessageDialog::new("标题", "内容").default_button(0);
+    assert!(dialog.show(&window).is_err());
+    let dialog = MessageDialog::new("标题", "内容").cancel_button(0);
+    assert!(dialog.show(&window).is_err());
+    let dialog = MessageDialog::new("标题", "内容")
+        .kind(MessageKind::Question)
+        .button("保存")
+        .button("不保存")
+        .default_button(2);
+    assert!(dialog.show(&window).is_err());
+    let dialog = MessageDialog::new("标题", "内容")
+        .button("保存")
+        .default_button(0)
+        .cancel_button(1);
+    assert!(dialog.show(&window).is_err());
+    assert!(MessageDialog::new("标题\0", "内容").show(&window).is_err());
+    assert!(confirm(&window, "标题\0", "内容").is_err());
+
+    // 销毁最后一个窗口默认会退出主循环，后面的测试还要用到
+    on_last_window_closed(|| false)?;
+    window.destroy();
+    clear_last_window_closed()?;
+
+    Ok(())
+}
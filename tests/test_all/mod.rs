@@ -1,17 +1,27 @@
 //! 测试每一个小部件功能的正确性。
 //! **请注意： UI组件应该在主线程中运行，并且不应该在多线程之间共享数据。**
 
+mod accelerator;
+#[cfg(target_os = "linux")]
+mod action;
 mod r#box;
 mod button;
 mod check_box;
+#[cfg(target_os = "linux")]
+mod clipboard;
 mod combo_box;
 mod control;
 mod date_time_picker;
 mod editable_combo_box;
 mod entry;
+mod file_dialog;
 mod group;
 mod label;
+#[cfg(target_os = "linux")]
+mod log_view;
 mod menu;
+#[cfg(target_os = "linux")]
+mod message_dialog;
 mod multi_line_entry;
 mod progress_bar;
 mod radio_buttons;
@@ -19,26 +29,45 @@ mod separator;
 mod slider;
 mod spin_box;
 mod tab;
+#[cfg(target_os = "linux")]
+mod validator;
 mod window;
+mod window_state;
 
-use system_ui::*;
+use {
+    std::sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+    system_ui::*,
+};
 
 #[test]
 fn test_all() -> anyhow::Result<()> {
     init()?;
 
     // ui操作必须单线程，所以这里收集所有要测试的函数顺序运行
+    accelerator::test_accelerator()?;
+    #[cfg(target_os = "linux")]
+    action::test_action()?;
     r#box::test_box()?;
     button::test_button()?;
     check_box::test_check_box()?;
+    #[cfg(target_os = "linux")]
+    clipboard::test_clipboard()?;
     combo_box::test_combo_box()?;
     control::test_control()?;
     date_time_picker::test_date_time_picker()?;
     editable_combo_box::test_editable_combo_box()?;
     entry::test_entry()?;
+    file_dialog::test_file_dialog()?;
     group::test_group()?;
     label::test_label()?;
+    #[cfg(target_os = "linux")]
+    log_view::test_log_view()?;
     menu::test_menu()?;
+    #[cfg(target_os = "linux")]
+    message_dialog::test_message_dialog()?;
     multi_line_entry::test_multi_line_entry()?;
     progress_bar::test_progress_bar()?;
     radio_buttons::test_radio_buttons()?;
@@ -46,7 +75,20 @@ fn test_all() -> anyhow::Result<()> {
     slider::test_slider()?;
     spin_box::test_spin_box()?;
     tab::test_tab()?;
+    #[cfg(target_os = "linux")]
+    validator::test_validator()?;
     window::test_window()?;
+    window_state::test_window_state()?;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// 运行主循环，直到此前通过 `queue_main()` 排队的函数都已运行
+pub fn run_queued() {
+    let done = Arc::new(AtomicBool::new(false));
+    let done_ = done.clone();
+    queue_main(move || done_.store(true, Ordering::Relaxed));
+    while !done.load(Ordering::Relaxed) {
+        unsafe { raw::uiMainStep(0) };
+    }
+}
@@ -17,5 +17,56 @@ pub fn test_multi_line_entry() -> anyhow::Result<()> {
     entry.set_read_only(true);
     assert!(entry.read_only());
 
+    #[cfg(target_os = "linux")]
+    {
+        // 测试行访问
+        entry.set_text("第一行\n第二行\n")?;
+        assert_eq!(3, entry.line_count());
+        assert_eq!(Some("第二行".into()), entry.line(1)?);
+        assert_eq!(Some("".into()), entry.line(2)?);
+        assert_eq!(None, entry.line(3)?);
+
+        // 测试光标、选择和插入
+        entry.set_cursor_position(3);
+        assert_eq!(3, entry.cursor_position());
+        entry.insert_at_cursor("，")?;
+        assert_eq!(Some("第一行，".into()), entry.line(0)?);
+        entry.select_range(4, 7);
+        assert_eq!(Some((4, 7)), entry.selection());
+        entry.set_cursor_position(-1);
+        assert_eq!(None, entry.selection());
+
+        // 测试自动滚动
+        assert!(!entry.auto_scroll());
+        entry.set_auto_scroll(true);
+        assert!(entry.auto_scroll());
+        entry.append("第三行")?;
+        entry.scroll_to_end();
+    }
+
+    // 测试拖放回调
+    #[cfg(target_os = "linux")]
+    {
+        entry.on_drop(|data| matches!(data, DropData::Paths(_)))?;
+        entry.on_drag_hover(|_entered| ())?;
+        entry.clear_drag_hover()?;
+        entry.clear_drop()?;
+    }
+
+    // 测试等宽字体和颜色
+    #[cfg(target_os = "linux")]
+    {
+        entry.set_font(Some(&FontDescriptor::monospace(10.5)))?;
+        let font = entry.font();
+        assert_eq!("Monospace", font.family);
+        assert!((10.5 - font.size).abs() < 0.1);
+        assert_eq!(TextWeight::NORMAL, font.weight);
+        assert_eq!(TextItalic::Normal, font.italic);
+        assert_eq!(TextStretch::Normal, font.stretch);
+        entry.set_text_color(Some(Color::rgb(0xe0e0e0)));
+        entry.set_background_color(Some(Color::rgb(0x202020)));
+        assert_eq!(Some("第一行，".into()), entry.line(0)?);
+    }
+
     Ok(())
 }
@@ -6,5 +6,62 @@ pub fn test_progress_bar() -> anyhow::Result<()> {
     progress_bar.set_value(50);
     assert_eq!(50, progress_bar.value());
 
+    // 测试状态
+    assert_eq!(ProgressState::Determinate(50), progress_bar.state());
+    progress_bar.set_state(ProgressState::Indeterminate);
+    assert_eq!(-1, progress_bar.value());
+    progress_bar.set_state(ProgressState::Determinate(200));
+    assert_eq!(ProgressState::Determinate(100), progress_bar.state());
+    #[cfg(target_os = "linux")]
+    {
+        assert!(!progress_bar.show_text());
+        progress_bar.set_show_text(true);
+        assert!(progress_bar.show_text());
+        progress_bar.set_text(Some("导出中"))?;
+        assert_eq!("导出中", progress_bar.text()?);
+        progress_bar.set_text(None)?;
+        assert_eq!("", progress_bar.text()?);
+
+        // 测试工作线程进度
+        let progress = Progress::new(&progress_bar, 200);
+        assert_eq!(ProgressState::Determinate(0), progress_bar.state());
+        assert_eq!("0%", progress_bar.text()?);
+        assert_eq!(None, progress.eta()?);
+        let worker = progress.clone();
+        std::thread::spawn(move || {
+            worker.set_message(Some("导出中"))?;
+            worker.advance(50)?;
+            worker.advance(50)
+        })
+        .join()
+        .unwrap()?;
+        assert_eq!(ProgressState::Determinate(50), progress.state()?);
+        assert!(progress.eta()?.is_some());
+        // 更新在主循环中显示
+        crate::run_queued();
+        assert_eq!(ProgressState::Determinate(50), progress_bar.state());
+        let text = progress_bar.text()?;
+        assert!(text.starts_with("导出中 – 50%, "), "{}", text);
+        assert!(text.ends_with(" left"), "{}", text);
+        progress.finish()?;
+        assert_eq!(ProgressState::Determinate(100), progress.state()?);
+        assert_eq!(None, progress.eta()?);
+        crate::run_queued();
+        assert_eq!(ProgressState::Determinate(100), progress_bar.state());
+        assert_eq!("导出中 – 100%", progress_bar.text()?);
+        progress.set_total(0)?;
+        assert_eq!(ProgressState::Indeterminate, progress.state()?);
+        crate::run_queued();
+        assert_eq!(-1, progress_bar.value());
+        assert_eq!("导出中", progress_bar.text()?);
+
+        // 测试取消
+        let cancel = Button::new("取消")?;
+        progress.cancel_button(&cancel)?;
+        assert!(!progress.is_cancelled());
+        progress.cancel();
+        assert!(progress.is_cancelled());
+    }
+
     Ok(())
 }
@@ -7,6 +7,35 @@ pub fn test_slider() -> anyhow::Result<()> {
     assert_eq!(5, slider.value());
     slider.set_has_tool_tip(true);
     assert!(slider.has_tool_tip());
+    #[cfg(target_os = "linux")]
+    assert_eq!(0, slider.precision());
+
+    #[cfg(target_os = "linux")]
+    {
+        // 测试小数值
+        let slider = Slider::new_f64(-1., 1., 0.01);
+        assert_eq!(-1., slider.value_f64());
+        assert_eq!(2, slider.precision());
+        slider.set_value_f64(0.25);
+        assert_eq!(0.25, slider.value_f64());
+        slider.set_value_f64(2.);
+        assert_eq!(1., slider.value_f64());
+        slider.set_increments(0.05, 0.5);
+        assert_eq!(0.05, slider.step());
+        assert_eq!(0.5, slider.page());
+        slider.set_precision(1);
+        assert_eq!(1, slider.precision());
+        slider.set_range_f64(0., 0.5);
+        assert_eq!(0.5, slider.value_f64());
+
+        // 测试方向和刻度
+        assert!(!slider.vertical());
+        slider.set_vertical(true);
+        assert!(slider.vertical());
+        slider.add_tick(0., Some("0 dB"))?;
+        slider.add_tick(0.25, None)?;
+        slider.clear_ticks();
+    }
 
     Ok(())
 }
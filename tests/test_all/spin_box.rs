@@ -5,6 +5,28 @@ pub fn test_spin_box() -> anyhow::Result<()> {
     assert_eq!(0, spinbox.value());
     spinbox.set_value(5);
     assert_eq!(5, spinbox.value());
+    #[cfg(target_os = "linux")]
+    {
+        spinbox.set_range(0, 3);
+        assert_eq!(3, spinbox.value());
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        // 测试小数值
+        let spinbox = Spinbox::new_f64(0., 1., 0.1);
+        assert_eq!(1, spinbox.precision());
+        spinbox.set_value_f64(0.5);
+        assert_eq!(0.5, spinbox.value_f64());
+        spinbox.set_precision(3);
+        spinbox.set_value_f64(0.125);
+        assert_eq!(0.125, spinbox.value_f64());
+        spinbox.set_increments(0.01, 0.1);
+        assert_eq!(0.01, spinbox.step());
+        assert_eq!(0.1, spinbox.page());
+        spinbox.set_range_f64(-1., 0.);
+        assert_eq!(0., spinbox.value_f64());
+    }
 
     Ok(())
 }
@@ -0,0 +1,62 @@
+use system_ui::*;
+
+pub fn test_validator() -> anyhow::Result<()> {
+    // 内置校验器
+    assert!(NonEmpty.validate(" a "));
+    assert!(!NonEmpty.validate("  "));
+    assert!(Email.validate("user@example.com"));
+    assert!(!Email.validate("user@example"));
+    assert!(!Email.validate("us er@example.com"));
+    let range = NumberRange::new(0., 10000.).thousands_separator(',');
+    assert!(range.validate("1,234.5"));
+    assert!(!range.validate("12345"));
+    assert_eq!(Some("-1,234,567.25".into()), range.format("-1234567.25"));
+    assert_eq!(None, range.format("abc"));
+    let even = |text: &str| text.parse::<i32>().is_ok_and(|n| n % 2 == 0);
+    assert!(even.validate("4"));
+
+    // 表单校验控制提交按钮
+    let submit = Button::new("提交")?;
+    let name = Entry::new();
+    let email = MultiLineEntry::new();
+    let name_validation = name.set_validator(NonEmpty)?;
+    let email_validation = email.set_validator(Email)?;
+    assert!(!name_validation.is_valid()?);
+    let form = FormValidator::new(Some(&submit));
+    form.field(&name_validation)?.field(&email_validation)?;
+    assert!(!submit.enabled());
+    name.set_text("name")?;
+    email.set_text("user@example.com")?;
+    assert!(form.validate()?);
+    assert!(submit.enabled());
+    name.set_text("")?;
+    assert!(!form.validate()?);
+    assert!(!submit.enabled());
+
+    // 格式化输入
+    let amount = EditableCombobox::new()?;
+    amount.set_text("1234567")?;
+    let amount_validation =
+        amount.set_validator(NumberRange::new(0., 1e9).thousands_separator(','))?;
+    assert!(amount_validation.is_valid()?);
+    assert_eq!("1,234,567", amount.text()?);
+
+    // 格式化后光标仍在同一个数字之后
+    let price = Entry::new();
+    let price_validation =
+        price.set_validator(NumberRange::new(0., 1e9).thousands_separator(','))?;
+    price.set_text("1234567")?;
+    price.select_range(3, 3);
+    assert!(price_validation.validate()?);
+    assert_eq!("1,234,567", price.text()?);
+    assert_eq!(4, price.cursor_position());
+
+    // 控件销毁后保留最后的校验状态，提交按钮销毁后表单仍可校验
+    price.destroy();
+    assert!(price_validation.validate()?);
+    submit.destroy();
+    name.set_text("name")?;
+    assert!(form.validate()?);
+
+    Ok(())
+}
@@ -1,7 +1,20 @@
+#[cfg(target_os = "linux")]
+use std::sync::{Arc, Mutex};
 use system_ui::*;
 
 pub fn test_window() -> anyhow::Result<()> {
     let window = Window::new("test", 80, 80, false)?;
+    // 窗口创建后不能再创建菜单
+    #[cfg(target_os = "linux")]
+    {
+        assert!(MenuBar::<()>::new().build(|_, _, _| ()).is_err());
+        // 动作的快捷键随加速键的修改而更新
+        let action = Action::new("window.reload", "刷新")?;
+        action.bind_shortcut(&window)?;
+        action.set_accelerator(Some("F5".parse()?))?;
+        action.set_accelerator(Some("Ctrl+R".parse()?))?;
+        action.unbind(&window)?;
+    }
     window.set_title("new_title")?;
     assert_eq!("new_title", window.title()?);
     window.set_fullscreen(true);
@@ -12,11 +25,170 @@ pub fn test_window() -> anyhow::Result<()> {
     assert_eq!((100, 100), window.content_size());
     window.set_margined(true);
     assert!(window.margined());
+    #[cfg(target_os = "linux")]
+    {
+        window.set_min_content_size(50, 50);
+        window.set_max_content_size(800, 0);
+        window.set_aspect_ratio(Some((4., 3.)));
+        window.set_aspect_ratio(None);
+        let icon = Image::new(16., 16.);
+        icon.append(&[255; 16 * 16 * 4], 16, 16, 16 * 4);
+        window.set_icon(Some(&icon));
+        drop(icon);
+        window.set_icon(None);
+        // 窗口显示前也能获取工作区
+        let (_, _, width, height) = window.work_area();
+        assert!(width > 0 && height > 0);
+        let (_, _, width, _) = screen_work_area();
+        assert!(width > 0);
+
+        // 每个进程使用不同的文件，测试结束后删除
+        window.set_fullscreen(false);
+        let path =
+            std::env::temp_dir().join(format!("system-ui-persist-{}.txt", std::process::id()));
+        let saved = WindowState {
+            x: 30,
+            y: 30,
+            width: 120,
+            height: 90,
+            ..Default::default()
+        };
+        saved.save(&path)?;
+        WindowState::persist(&window, &path)?;
+        assert_eq!((120, 90), window.content_size());
+        window.set_content_size(150, 110);
+        WindowState::remember(&window)?;
+        assert!(WindowState::save_persisted(&window)?);
+        assert!(WindowState::forget(&window)?);
+        assert!(!WindowState::save_persisted(&window)?);
+        let state = WindowState::load(&path)?;
+        std::fs::remove_file(&path)?;
+        assert_eq!((150, 110), (state.width, state.height));
+        assert_eq!(WindowState::from_window(&window), state);
+        state.apply(&window);
+
+        // 销毁时自动保存
+        let persisted = Window::new_dialog(&window, "persisted", 40, 40)?;
+        WindowState::persist(&persisted, &path)?;
+        persisted.set_content_size(130, 70);
+        persisted.destroy();
+        let state = WindowState::load(&path)?;
+        std::fs::remove_file(&path)?;
+        assert_eq!((130, 70), (state.width, state.height));
+    }
     window.set_position(40, 40);
     assert_eq!((40, 40), window.position());
     window.show();
     window.hide();
     assert!(!window.focused());
 
+    #[cfg(target_os = "linux")]
+    {
+        let dialog = Window::new_dialog(&window, "dialog", 40, 40)?;
+        assert_eq!("dialog", dialog.title()?);
+        assert_eq!(2, windows()?.len());
+        assert!(active_window()?.is_none());
+        on_last_window_closed(|| false)?;
+        clear_last_window_closed()?;
+        // 不在模态循环中时 end_modal 只销毁对话框
+        dialog.end_modal(true);
+        let dialog = Window::new_dialog(&window, "dialog", 40, 40)?;
+        // 对话框默认在关闭时销毁
+        dialog.close();
+
+        // 在按钮回调中运行模态对话框，并由对话框中的按钮结束
+        let parent = window.as_ptr_mut() as isize;
+        let result = Arc::new(Mutex::new(None));
+        let result_ = result.clone();
+        let open = Button::new("打开")?;
+        open.on_clicked(
+            move |_, _| {
+                let parent = Window::from_ptr(parent as _);
+                let dialog = Window::new_dialog(&parent, "modal", 40, 40).unwrap();
+                let ok = Button::new("确定").unwrap();
+                dialog.set_child(&ok);
+                let dialog_ = dialog.as_ptr_mut() as isize;
+                ok.on_clicked(
+                    move |_, _| Window::from_ptr(dialog_ as _).end_modal(true),
+                    &mut (),
+                )
+                .unwrap();
+                let ok = ok.as_ptr_mut() as isize;
+                queue_main(move || Button::from_ptr(ok as _).click());
+                *result_.lock().unwrap() = Some(dialog.run_modal::<bool>());
+            },
+            &mut (),
+        )?;
+        open.click();
+        assert_eq!(Some(true), *result.lock().unwrap());
+        assert_eq!(1, windows()?.len());
+        open.destroy();
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        window.set_keep_above(true);
+        assert!(window.keep_above());
+        window.set_keep_above(false);
+        assert!(!window.keep_above());
+        window.on_state_changed(|_, _| (), &mut ())?;
+        window.clear_state_changed()?;
+        assert!(!window.is_maximized());
+        assert!(!window.is_minimized());
+        window.maximize();
+        window.restore();
+    }
+
+    // 键盘焦点
+    #[cfg(target_os = "linux")]
+    {
+        let vbox = Box::new_vertical();
+        let first = Entry::new();
+        let second = Entry::new();
+        vbox.append(&first, false);
+        vbox.append(&second, false);
+        window.set_child(&vbox);
+        first.on_focus_in(|| ())?;
+        first.on_focus_out(|| ())?;
+        second.focus();
+        assert!(second.has_focus());
+        assert!(!first.has_focus());
+        assert!(vbox.has_focus());
+        let focused = window.focused_control::<Entry>().map(|c| c.as_ptr());
+        assert_eq!(Some(second.as_ptr()), focused);
+        // 类型不符时返回 None
+        assert!(window.focused_control::<Button>().is_none());
+        assert!(window.focused_control::<MultiLineEntry>().is_none());
+        // 回调中可以移回焦点并注销自身
+        let ptr = first.as_ptr_mut() as isize;
+        first.on_focus_out(move || {
+            let first = Entry::from_ptr(ptr as _);
+            first.clear_focus_out().unwrap();
+            first.focus();
+        })?;
+        first.focus();
+        second.focus();
+        first.clear_focus_in()?;
+        first.clear_focus_out()?;
+    }
+
+    // 没有菜单栏的窗口也能添加快捷键
+    #[cfg(target_os = "linux")]
+    {
+        window.add_shortcut("Ctrl+F".parse()?, |w| assert!(w.focused()))?;
+        window.add_shortcut("Esc".parse()?, |w| w.close())?;
+        window.add_shortcut("Esc".parse()?, |_| ())?;
+        window.remove_shortcut("Ctrl+F".parse()?)?;
+        window.remove_shortcut("Ctrl+Shift+P".parse()?)?;
+
+        // 销毁的窗口可以安全地从动作解绑
+        let action = Action::new("window.find", "查找")?;
+        action.set_accelerator(Some("Ctrl+F".parse()?))?;
+        let dialog = Window::new_dialog(&window, "action", 40, 40)?;
+        action.bind_shortcut(&dialog)?;
+        dialog.destroy();
+        action.unbind(&dialog)?;
+    }
+
     Ok(())
 }
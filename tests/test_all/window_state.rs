@@ -0,0 +1,26 @@
+use system_ui::*;
+
+pub fn test_window_state() -> anyhow::Result<()> {
+    let state = WindowState {
+        x: 10,
+        y: -20,
+        width: 640,
+        height: 480,
+        maximized: true,
+        fullscreen: false,
+    };
+    assert_eq!(state, state.to_string().parse()?);
+    // 每个进程使用不同的文件
+    let path =
+        std::env::temp_dir().join(format!("system-ui-window-state-{}.txt", std::process::id()));
+    state.save(&path)?;
+    assert_eq!(state, WindowState::load(&path)?);
+    std::fs::remove_file(&path)?;
+
+    // 未知的键会被忽略
+    let state: WindowState = "width=100\nheight=50\ncolor=red\n".parse()?;
+    assert_eq!((100, 50), (state.width, state.height));
+    assert!("width=wide".parse::<WindowState>().is_err());
+
+    Ok(())
+}